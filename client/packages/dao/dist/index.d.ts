@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
 import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions } from '@stellar/stellar-sdk/contract';
-import type { u64, i128 } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,17 +10,202 @@ export declare const networks: {
         readonly contractId: "CCS6L527IZSNIOGLAYPJLGJXNZ7XY3YMUY2EVRDXA7SOFKS2PGPG56RV";
     };
 };
-export declare const Errors: {};
+export type DaoStatus = {
+    tag: "Fundraising";
+    values: void;
+} | {
+    tag: "Funded";
+    values: void;
+} | {
+    tag: "Refunding";
+    values: void;
+};
+export interface Milestone {
+    amount: i128;
+    description: string;
+    due_date: u64;
+}
+export interface DaoConfig {
+    accepted_asset: string;
+    approval_threshold_bps: u32;
+    compliance_enabled: boolean;
+    funding_period: u64;
+    grace_period: u64;
+    hard_cap: i128;
+    lockup_period: u64;
+    max_active_proposals: u32;
+    max_investment: i128;
+    min_investment: i128;
+    profit_distributor: string;
+    proposal_threshold: i128;
+    quorum_bps: u32;
+    timelock_delay: u64;
+    token_name: string;
+    token_symbol: string;
+    voting_delay: u64;
+    voting_period: u64;
+}
+export interface RaiseTerms {
+    accepted_asset: string;
+    funding_deadline: u64;
+    funding_goal: i128;
+    hard_cap: i128;
+    max_investment: i128;
+    min_investment: i128;
+    total_raised: i128;
+}
+export type ContentBody = {
+    tag: "None";
+    values: void;
+} | {
+    tag: "Inline";
+    values: readonly [Buffer];
+} | {
+    tag: "Uri";
+    values: readonly [string];
+};
+export interface Content {
+    body: ContentBody;
+    content_hash: Buffer;
+    title: string;
+}
+export type ProposalStatus = {
+    tag: "Pending";
+    values: void;
+} | {
+    tag: "Active";
+    values: void;
+} | {
+    tag: "Defeated";
+    values: void;
+} | {
+    tag: "Succeeded";
+    values: void;
+} | {
+    tag: "Queued";
+    values: void;
+} | {
+    tag: "Executed";
+    values: void;
+} | {
+    tag: "Cancelled";
+    values: void;
+} | {
+    tag: "Expired";
+    values: void;
+};
+export type VoteChoice = {
+    tag: "For";
+    values: void;
+} | {
+    tag: "Against";
+    values: void;
+} | {
+    tag: "Abstain";
+    values: void;
+};
+export interface Ballot {
+    choice: VoteChoice;
+    reason: string;
+    weight: i128;
+}
+export interface VestingGrant {
+    amount: i128;
+    beneficiary: string;
+    cliff: u64;
+    duration: u64;
+}
+export interface VestingSchedule {
+    claimed: i128;
+    grant: VestingGrant;
+    start: u64;
+}
+export type ProposalAction = {
+    tag: "None";
+    values: void;
+} | {
+    tag: "Payout";
+    values: readonly [string, i128];
+} | {
+    tag: "ReleaseMilestone";
+    values: readonly [u32];
+} | {
+    tag: "Refund";
+    values: void;
+} | {
+    tag: "GrantVesting";
+    values: readonly [VestingGrant];
+} | {
+    tag: "MintShares";
+    values: readonly [Array<readonly [string, i128]>];
+} | {
+    tag: "SetShareMaxSupply";
+    values: readonly [i128];
+} | {
+    tag: "RemoveShareMaxSupply";
+    values: void;
+} | {
+    tag: "SetShareAdmin";
+    values: readonly [string];
+};
+export interface Proposal {
+    action: ProposalAction;
+    content: Content;
+    deadline: u64;
+    eta: u64;
+    id: u64;
+    proposer: string;
+    start: u64;
+    status: ProposalStatus;
+    votes_abstain: i128;
+    votes_against: i128;
+    votes_for: i128;
+}
+export type DataKey = {
+    tag: "Proposal";
+    values: readonly [u64];
+} | {
+    tag: "Ballot";
+    values: readonly [u64, string];
+} | {
+    tag: "ActiveProposals";
+    values: readonly [string];
+} | {
+    tag: "Allowed";
+    values: readonly [string];
+} | {
+    tag: "Distribution";
+    values: readonly [u32];
+};
+export type InvestorGate = {
+    tag: "Open";
+    values: void;
+} | {
+    tag: "Allowlist";
+    values: void;
+} | {
+    tag: "Attestation";
+    values: readonly [string];
+};
+export declare const Errors: {
+    1: {
+        message: string;
+    };
+    2: {
+        message: string;
+    };
+};
 export interface Client {
     /**
      * Construct and simulate a initialize_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    initialize_dao: ({ name, description, funding_goal, creator, token_contract_id }: {
-        name: string;
-        description: string;
-        funding_goal: u64;
+    initialize_dao: ({ info, funding_goal, creator, token_contract_id, config, milestones }: {
+        info: Content;
+        funding_goal: i128;
         creator: string;
         token_contract_id: string;
+        config: DaoConfig;
+        milestones: Array<Milestone>;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -40,7 +225,27 @@ export interface Client {
      */
     invest: ({ investor, amount }: {
         investor: string;
-        amount: u64;
+        amount: i128;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a reinvest_profit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    reinvest_profit: ({ investor, amount }: {
+        investor: string;
+        amount: i128;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -58,8 +263,10 @@ export interface Client {
     /**
      * Construct and simulate a create_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    create_proposal: ({ details }: {
-        details: string;
+    create_proposal: ({ proposer, content, action }: {
+        proposer: string;
+        content: Content;
+        action: ProposalAction;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -77,10 +284,11 @@ export interface Client {
     /**
      * Construct and simulate a vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    vote: ({ voter, proposal_id, support }: {
+    vote: ({ voter, proposal_id, choice, reason }: {
         voter: string;
         proposal_id: u64;
-        support: boolean;
+        choice: VoteChoice;
+        reason: Option<string>;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -95,6 +303,25 @@ export interface Client {
          */
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a queue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    queue: ({ proposal_id }: {
+        proposal_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
     /**
      * Construct and simulate a execute_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
@@ -115,11 +342,10 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a record_distribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a claim_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    record_distribution: ({ timestamp, amount }: {
-        timestamp: u64;
-        amount: u64;
+    claim_refund: ({ investor }: {
+        investor: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -133,11 +359,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<i128>>;
     /**
-     * Construct and simulate a get_distribution_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a claim_vested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_distribution_history: (options?: {
+    claim_vested: ({ beneficiary }: {
+        beneficiary: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -150,11 +378,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<readonly [u64, u64]>>>;
+    }) => Promise<AssembledTransaction<i128>>;
     /**
-     * Construct and simulate a get_proposals_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_vesting_schedules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_proposals_count: (options?: {
+    get_vesting_schedules: ({ beneficiary }: {
+        beneficiary: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -167,12 +397,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<Array<VestingSchedule>>>;
     /**
-     * Construct and simulate a get_proposal_details transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_claimable_vested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_proposal_details: ({ proposal_id }: {
-        proposal_id: u64;
+    get_claimable_vested: ({ beneficiary }: {
+        beneficiary: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -186,12 +416,47 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<i128>>;
     /**
-     * Construct and simulate a get_proposal_votes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_milestones transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_proposal_votes: ({ proposal_id }: {
-        proposal_id: u64;
+    get_milestones: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<Milestone>>>;
+    /**
+     * Construct and simulate a get_next_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_next_milestone: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a ragequit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    ragequit: ({ investor, shares }: {
+        investor: string;
+        shares: i128;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -207,9 +472,10 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<i128>>;
     /**
-     * Construct and simulate a get_proposal_executed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a cancel_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_proposal_executed: ({ proposal_id }: {
+    cancel_proposal: ({ caller, proposal_id }: {
+        caller: string;
         proposal_id: u64;
     }, options?: {
         /**
@@ -224,12 +490,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<boolean>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a set_investor_gate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_voting_power: ({ voter }: {
-        voter: string;
+    set_investor_gate: ({ gate }: {
+        gate: InvestorGate;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -243,11 +509,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_investor_gate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_total_raised: (options?: {
+    get_investor_gate: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -260,11 +526,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<InvestorGate>>;
     /**
-     * Construct and simulate a is_funding_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a allow_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    is_funding_goal_reached: (options?: {
+    allow_investor: ({ investor }: {
+        investor: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -277,11 +545,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<boolean>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_investors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a disallow_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_investors: (options?: {
+    disallow_investor: ({ investor }: {
+        investor: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -294,11 +564,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<string>>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a is_investor_allowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_token_contract: (options?: {
+    is_investor_allowed: ({ account }: {
+        account: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -311,11 +583,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<boolean>>;
     /**
-     * Construct and simulate a get_investments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a set_share_transfers_enabled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_investments: (options?: {
+    set_share_transfers_enabled: ({ enabled }: {
+        enabled: boolean;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -328,11 +602,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Map<string, u64>>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a freeze_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_name: (options?: {
+    freeze_shares: ({ account }: {
+        account: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -345,11 +621,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_description transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a unfreeze_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_description: (options?: {
+    unfreeze_shares: ({ account }: {
+        account: string;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -362,11 +640,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_funding_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a clawback_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_funding_goal: (options?: {
+    clawback_shares: ({ from, amount }: {
+        from: string;
+        amount: i128;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -379,11 +660,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a set_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_creator: (options?: {
+    set_guardian: ({ new_guardian }: {
+        new_guardian: Option<string>;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -396,12 +679,30 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a get_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_guardian: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<string>>>;
     /**
-     * Construct and simulate a initialize_ptc transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a record_distribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    initialize_ptc: ({ admin }: {
-        admin: string;
+    record_distribution: ({ timestamp, amount }: {
+        timestamp: u64;
+        amount: i128;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -417,9 +718,9 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_distribution_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_admin: (options?: {
+    get_distribution_count: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -432,13 +733,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<u32>>;
     /**
-     * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_distribution_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    mint: ({ to, amount }: {
-        to: string;
-        amount: i128;
+    get_distribution_history: ({ start, limit }: {
+        start: u32;
+        limit: u32;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -452,13 +753,29 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<Array<readonly [u64, i128]>>>;
     /**
-     * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_proposals_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    burn: ({ from, amount }: {
-        from: string;
-        amount: i128;
+    get_proposals_count: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_proposal: ({ proposal_id }: {
+        proposal_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -472,14 +789,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<Proposal>>;
     /**
-     * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_proposals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    transfer: ({ from, to, amount }: {
-        from: string;
-        to: string;
-        amount: i128;
+    get_proposals: ({ start, limit }: {
+        start: u64;
+        limit: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -493,14 +809,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<Array<Proposal>>>;
     /**
-     * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_proposer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    approve: ({ owner, spender, amount }: {
-        owner: string;
-        spender: string;
-        amount: i128;
+    get_proposer: ({ proposal_id }: {
+        proposal_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -514,13 +828,31 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_proposal_details transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_proposal_details: ({ proposal_id }: {
+        proposal_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Content>>;
     /**
-     * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_proposal_votes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    allowance: ({ owner, spender }: {
-        owner: string;
-        spender: string;
+    get_proposal_votes: ({ proposal_id }: {
+        proposal_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -536,13 +868,10 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<i128>>;
     /**
-     * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_proposal_executed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    transfer_from: ({ spender, from, to, amount }: {
-        spender: string;
-        from: string;
-        to: string;
-        amount: i128;
+    get_proposal_executed: ({ proposal_id }: {
+        proposal_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -556,12 +885,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<boolean>>;
     /**
-     * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_proposal_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    balance: ({ who }: {
-        who: string;
+    get_proposal_state: ({ proposal_id }: {
+        proposal_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -575,11 +904,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<ProposalStatus>>;
     /**
-     * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_proposal_tally transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    total_supply: (options?: {
+    get_proposal_tally: ({ proposal_id }: {
+        proposal_id: u64;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -592,11 +923,364 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<readonly [i128, i128, i128]>>;
+    /**
+     * Construct and simulate a get_ballot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_ballot: ({ proposal_id, voter }: {
+        proposal_id: u64;
+        voter: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Option<Ballot>>>;
+    /**
+     * Construct and simulate a get_proposal_eta transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_proposal_eta: ({ proposal_id }: {
+        proposal_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_proposal_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_proposal_deadline: ({ proposal_id }: {
+        proposal_id: u64;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_voting_power: ({ voter }: {
+        voter: string;
+    }, options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_total_raised: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_total_reinvested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_total_reinvested: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a is_funding_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    is_funding_goal_reached: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a get_raise_terms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_raise_terms: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<RaiseTerms>>;
+    /**
+     * Construct and simulate a get_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_status: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<DaoStatus>>;
+    /**
+     * Construct and simulate a get_investors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_investors: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Array<string>>>;
+    /**
+     * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_token_contract: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_investments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_investments: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Map<string, i128>>>;
+    /**
+     * Construct and simulate a get_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_info: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<Content>>;
+    /**
+     * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_name: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_description transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_description: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<ContentBody>>;
+    /**
+     * Construct and simulate a get_funding_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_funding_goal: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<i128>>;
+    /**
+     * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_config: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<DaoConfig>>;
+    /**
+     * Construct and simulate a get_funding_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_funding_deadline: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<u64>>;
+    /**
+     * Construct and simulate a get_factory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_factory: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_creator: (options?: {
+        /**
+         * The fee to pay for the transaction. Default: BASE_FEE
+         */
+        fee?: number;
+        /**
+         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+         */
+        timeoutInSeconds?: number;
+        /**
+         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+         */
+        simulate?: boolean;
+    }) => Promise<AssembledTransaction<string>>;
 }
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
     static deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { factory }: {
+        factory: string;
+    }, 
     /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -609,35 +1293,62 @@ export declare class Client extends ContractClient {
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
         initialize_dao: (json: string) => AssembledTransaction<null>;
-        invest: (json: string) => AssembledTransaction<null>;
+        invest: (json: string) => AssembledTransaction<bigint>;
+        reinvest_profit: (json: string) => AssembledTransaction<null>;
         create_proposal: (json: string) => AssembledTransaction<bigint>;
         vote: (json: string) => AssembledTransaction<null>;
+        queue: (json: string) => AssembledTransaction<bigint>;
         execute_proposal: (json: string) => AssembledTransaction<null>;
+        claim_refund: (json: string) => AssembledTransaction<bigint>;
+        claim_vested: (json: string) => AssembledTransaction<bigint>;
+        get_vesting_schedules: (json: string) => AssembledTransaction<VestingSchedule[]>;
+        get_claimable_vested: (json: string) => AssembledTransaction<bigint>;
+        get_milestones: (json: string) => AssembledTransaction<Milestone[]>;
+        get_next_milestone: (json: string) => AssembledTransaction<number>;
+        ragequit: (json: string) => AssembledTransaction<bigint>;
+        cancel_proposal: (json: string) => AssembledTransaction<null>;
+        set_investor_gate: (json: string) => AssembledTransaction<null>;
+        get_investor_gate: (json: string) => AssembledTransaction<InvestorGate>;
+        allow_investor: (json: string) => AssembledTransaction<null>;
+        disallow_investor: (json: string) => AssembledTransaction<null>;
+        is_investor_allowed: (json: string) => AssembledTransaction<boolean>;
+        set_share_transfers_enabled: (json: string) => AssembledTransaction<null>;
+        freeze_shares: (json: string) => AssembledTransaction<null>;
+        unfreeze_shares: (json: string) => AssembledTransaction<null>;
+        clawback_shares: (json: string) => AssembledTransaction<null>;
+        set_guardian: (json: string) => AssembledTransaction<null>;
+        get_guardian: (json: string) => AssembledTransaction<Option<string>>;
         record_distribution: (json: string) => AssembledTransaction<null>;
+        get_distribution_count: (json: string) => AssembledTransaction<number>;
         get_distribution_history: (json: string) => AssembledTransaction<(readonly [bigint, bigint])[]>;
         get_proposals_count: (json: string) => AssembledTransaction<bigint>;
-        get_proposal_details: (json: string) => AssembledTransaction<string>;
+        get_proposal: (json: string) => AssembledTransaction<Proposal>;
+        get_proposals: (json: string) => AssembledTransaction<Proposal[]>;
+        get_proposer: (json: string) => AssembledTransaction<string>;
+        get_proposal_details: (json: string) => AssembledTransaction<Content>;
         get_proposal_votes: (json: string) => AssembledTransaction<bigint>;
         get_proposal_executed: (json: string) => AssembledTransaction<boolean>;
+        get_proposal_state: (json: string) => AssembledTransaction<ProposalStatus>;
+        get_proposal_tally: (json: string) => AssembledTransaction<readonly [bigint, bigint, bigint]>;
+        get_ballot: (json: string) => AssembledTransaction<Option<Ballot>>;
+        get_proposal_eta: (json: string) => AssembledTransaction<bigint>;
+        get_proposal_deadline: (json: string) => AssembledTransaction<bigint>;
         get_voting_power: (json: string) => AssembledTransaction<bigint>;
         get_total_raised: (json: string) => AssembledTransaction<bigint>;
+        get_total_reinvested: (json: string) => AssembledTransaction<bigint>;
         is_funding_goal_reached: (json: string) => AssembledTransaction<boolean>;
+        get_raise_terms: (json: string) => AssembledTransaction<RaiseTerms>;
+        get_status: (json: string) => AssembledTransaction<DaoStatus>;
         get_investors: (json: string) => AssembledTransaction<string[]>;
         get_token_contract: (json: string) => AssembledTransaction<string>;
         get_investments: (json: string) => AssembledTransaction<Map<string, bigint>>;
+        get_info: (json: string) => AssembledTransaction<Content>;
         get_name: (json: string) => AssembledTransaction<string>;
-        get_description: (json: string) => AssembledTransaction<string>;
+        get_description: (json: string) => AssembledTransaction<ContentBody>;
         get_funding_goal: (json: string) => AssembledTransaction<bigint>;
+        get_config: (json: string) => AssembledTransaction<DaoConfig>;
+        get_funding_deadline: (json: string) => AssembledTransaction<bigint>;
+        get_factory: (json: string) => AssembledTransaction<string>;
         get_creator: (json: string) => AssembledTransaction<string>;
-        initialize_ptc: (json: string) => AssembledTransaction<null>;
-        get_admin: (json: string) => AssembledTransaction<string>;
-        mint: (json: string) => AssembledTransaction<null>;
-        burn: (json: string) => AssembledTransaction<null>;
-        transfer: (json: string) => AssembledTransaction<null>;
-        approve: (json: string) => AssembledTransaction<null>;
-        allowance: (json: string) => AssembledTransaction<bigint>;
-        transfer_from: (json: string) => AssembledTransaction<null>;
-        balance: (json: string) => AssembledTransaction<bigint>;
-        total_supply: (json: string) => AssembledTransaction<bigint>;
     };
 }
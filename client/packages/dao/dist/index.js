@@ -13,79 +13,155 @@ export const networks = {
         contractId: "CCS6L527IZSNIOGLAYPJLGJXNZ7XY3YMUY2EVRDXA7SOFKS2PGPG56RV",
     }
 };
-export const Errors = {};
+export const Errors = {
+    1: { message: "Overflow" },
+    2: { message: "DivisionByZero" }
+};
 export class Client extends ContractClient {
     options;
     static async deploy(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { factory }, 
     /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
     options) {
-        return ContractClient.deploy(null, options);
+        return ContractClient.deploy({ factory }, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9kYW8AAAAAAAUAAAAAAAAABG5hbWUAAAARAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAARAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAAGAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEXRva2VuX2NvbnRyYWN0X2lkAAAAAAAAEwAAAAA=",
-            "AAAAAAAAAAAAAAAGaW52ZXN0AAAAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABmFtb3VudAAAAAAABgAAAAA=",
-            "AAAAAAAAAAAAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAEAAAAAAAAAB2RldGFpbHMAAAAAEQAAAAEAAAAG",
-            "AAAAAAAAAAAAAAAEdm90ZQAAAAMAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAHc3VwcG9ydAAAAAABAAAAAA==",
+        super(new ContractSpec(["AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAAgAAAAAAAAAIT3ZlcmZsb3cAAAABAAAAAAAAAA5EaXZpc2lvbkJ5WmVybwAAAAAAAg==",
+            "AAAAAgAAAAAAAAAAAAAACURhb1N0YXR1cwAAAAAAAAMAAAAAAAAAAAAAAAtGdW5kcmFpc2luZwAAAAAAAAAAAAAAAAZGdW5kZWQAAAAAAAAAAAAAAAAACVJlZnVuZGluZwAAAA==",
+            "AAAAAQAAAAAAAAAAAAAACU1pbGVzdG9uZQAAAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAIZHVlX2RhdGUAAAAG",
+            "AAAAAQAAAAAAAAAAAAAACURhb0NvbmZpZwAAAAAAABIAAAAAAAAADmFjY2VwdGVkX2Fzc2V0AAAAAAATAAAAAAAAABZhcHByb3ZhbF90aHJlc2hvbGRfYnBzAAAAAAAEAAAAAAAAABJjb21wbGlhbmNlX2VuYWJsZWQAAAAAAAEAAAAAAAAADmZ1bmRpbmdfcGVyaW9kAAAAAAAGAAAAAAAAAAxncmFjZV9wZXJpb2QAAAAGAAAAAAAAAAhoYXJkX2NhcAAAAAsAAAAAAAAADWxvY2t1cF9wZXJpb2QAAAAAAAAGAAAAAAAAABRtYXhfYWN0aXZlX3Byb3Bvc2FscwAAAAQAAAAAAAAADm1heF9pbnZlc3RtZW50AAAAAAALAAAAAAAAAA5taW5faW52ZXN0bWVudAAAAAAACwAAAAAAAAAScHJvZml0X2Rpc3RyaWJ1dG9yAAAAAAATAAAAAAAAABJwcm9wb3NhbF90aHJlc2hvbGQAAAAAAAsAAAAAAAAACnF1b3J1bV9icHMAAAAAAAQAAAAAAAAADnRpbWVsb2NrX2RlbGF5AAAAAAAGAAAAAAAAAAp0b2tlbl9uYW1lAAAAAAAQAAAAAAAAAAx0b2tlbl9zeW1ib2wAAAAQAAAAAAAAAAx2b3RpbmdfZGVsYXkAAAAGAAAAAAAAAA12b3RpbmdfcGVyaW9kAAAAAAAABg==",
+            "AAAAAQAAAAAAAAAAAAAAClJhaXNlVGVybXMAAAAAAAcAAAAAAAAADmFjY2VwdGVkX2Fzc2V0AAAAAAATAAAAAAAAABBmdW5kaW5nX2RlYWRsaW5lAAAABgAAAAAAAAAMZnVuZGluZ19nb2FsAAAACwAAAAAAAAAIaGFyZF9jYXAAAAALAAAAAAAAAA5tYXhfaW52ZXN0bWVudAAAAAAACwAAAAAAAAAObWluX2ludmVzdG1lbnQAAAAAAAsAAAAAAAAADHRvdGFsX3JhaXNlZAAAAAs=",
+            "AAAAAgAAAAAAAAAAAAAAC0NvbnRlbnRCb2R5AAAAAAMAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAGSW5saW5lAAAAAAABAAAADgAAAAEAAAAAAAAAA1VyaQAAAAABAAAAEA==",
+            "AAAAAQAAAAAAAAAAAAAAB0NvbnRlbnQAAAAAAwAAAAAAAAAEYm9keQAAB9AAAAALQ29udGVudEJvZHkAAAAAAAAAAAxjb250ZW50X2hhc2gAAAPuAAAAIAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+            "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsU3RhdHVzAAAAAAAIAAAAAAAAAAAAAAAHUGVuZGluZwAAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAAAAAAAAAAACERlZmVhdGVkAAAAAAAAAAAAAAAJU3VjY2VlZGVkAAAAAAAAAAAAAAAAAAAGUXVldWVkAAAAAAAAAAAAAAAAAAhFeGVjdXRlZAAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAAAAAAAAAAAAAAAAB0V4cGlyZWQA",
+            "AAAAAgAAAAAAAAAAAAAAClZvdGVDaG9pY2UAAAAAAAMAAAAAAAAAAAAAAANGb3IAAAAAAAAAAAAAAAAHQWdhaW5zdAAAAAAAAAAAAAAAAAdBYnN0YWluAA==",
+            "AAAAAQAAAAAAAAAAAAAABkJhbGxvdAAAAAAAAwAAAAAAAAAGY2hvaWNlAAAAAAfQAAAAClZvdGVDaG9pY2UAAAAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAAAAAAZ3ZWlnaHQAAAAAAAs=",
+            "AAAAAQAAAAAAAAAAAAAADFZlc3RpbmdHcmFudAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAAAAAAFY2xpZmYAAAAAAAAGAAAAAAAAAAhkdXJhdGlvbgAAAAY=",
+            "AAAAAQAAAAAAAAAAAAAAD1Zlc3RpbmdTY2hlZHVsZQAAAAADAAAAAAAAAAdjbGFpbWVkAAAAAAsAAAAAAAAABWdyYW50AAAAAAAH0AAAAAxWZXN0aW5nR3JhbnQAAAAAAAAABXN0YXJ0AAAAAAAABg==",
+            "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAJAAAAAAAAAAAAAAAETm9uZQAAAAEAAAAAAAAABlBheW91dAAAAAAAAgAAABMAAAALAAAAAQAAAAAAAAAQUmVsZWFzZU1pbGVzdG9uZQAAAAEAAAAEAAAAAAAAAAAAAAAGUmVmdW5kAAAAAAABAAAAAAAAAAxHcmFudFZlc3RpbmcAAAABAAAH0AAAAAxWZXN0aW5nR3JhbnQAAAABAAAAAAAAAApNaW50U2hhcmVzAAAAAAABAAAD6gAAA+0AAAACAAAAEwAAAAsAAAABAAAAAAAAABFTZXRTaGFyZU1heFN1cHBseQAAAAAAAAEAAAALAAAAAAAAAAAAAAAUUmVtb3ZlU2hhcmVNYXhTdXBwbHkAAAABAAAAAAAAAA1TZXRTaGFyZUFkbWluAAAAAAAAAQAAABM=",
+            "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAACwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAAAAAAB2NvbnRlbnQAAAAH0AAAAAdDb250ZW50AAAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAANldGEAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAFc3RhcnQAAAAAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAAAAAAAAAAANdm90ZXNfYWJzdGFpbgAAAAAAAAsAAAAAAAAADXZvdGVzX2FnYWluc3QAAAAAAAALAAAAAAAAAAl2b3Rlc19mb3IAAAAAAAAL",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAEAAAAAAAAACFByb3Bvc2FsAAAAAQAAAAYAAAABAAAAAAAAAAZCYWxsb3QAAAAAAAIAAAAGAAAAEwAAAAEAAAAAAAAAD0FjdGl2ZVByb3Bvc2FscwAAAAABAAAAEwAAAAEAAAAAAAAAB0FsbG93ZWQAAAAAAQAAABMAAAABAAAAAAAAAAxEaXN0cmlidXRpb24AAAABAAAABA==",
+            "AAAAAgAAAAAAAAAAAAAADEludmVzdG9yR2F0ZQAAAAMAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAJQWxsb3dsaXN0AAAAAAAAAQAAAAAAAAALQXR0ZXN0YXRpb24AAAAAAQAAABM=",
+            "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAAB2ZhY3RvcnkAAAAAEwAAAAA=",
+            "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9kYW8AAAAAAAYAAAAAAAAABGluZm8AAAfQAAAAB0NvbnRlbnQAAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAALAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEXRva2VuX2NvbnRyYWN0X2lkAAAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAACURhb0NvbmZpZwAAAAAAAAAAAAAKbWlsZXN0b25lcwAAAAAD6gAAB9AAAAAJTWlsZXN0b25lAAAAAAAAAA==",
+            "AAAAAAAAAAAAAAAGaW52ZXN0AAAAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAPcmVpbnZlc3RfcHJvZml0AAAAAAIAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+            "AAAAAAAAAAAAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAMAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAHY29udGVudAAAAAfQAAAAB0NvbnRlbnQAAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAAOUHJvcG9zYWxBY3Rpb24AAAAAAAEAAAAG",
+            "AAAAAAAAAAAAAAAEdm90ZQAAAAQAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAGY2hvaWNlAAAAAAfQAAAAClZvdGVDaG9pY2UAAAAAAAAAAAAGcmVhc29uAAAAAAPoAAAAEAAAAAA=",
+            "AAAAAAAAAAAAAAAFcXVldWUAAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAY=",
             "AAAAAAAAAAAAAAAQZXhlY3V0ZV9wcm9wb3NhbAAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAAA",
-            "AAAAAAAAAAAAAAATcmVjb3JkX2Rpc3RyaWJ1dGlvbgAAAAACAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAYAAAAA",
-            "AAAAAAAAAAAAAAAYZ2V0X2Rpc3RyaWJ1dGlvbl9oaXN0b3J5AAAAAAAAAAEAAAPqAAAD7QAAAAIAAAAGAAAABg==",
+            "AAAAAAAAAAAAAAAMY2xhaW1fcmVmdW5kAAAAAQAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAAMY2xhaW1fdmVzdGVkAAAAAQAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAVZ2V0X3Zlc3Rpbmdfc2NoZWR1bGVzAAAAAAAAAQAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAEAAAPqAAAH0AAAAA9WZXN0aW5nU2NoZWR1bGUA",
+            "AAAAAAAAAAAAAAAUZ2V0X2NsYWltYWJsZV92ZXN0ZWQAAAABAAAAAAAAAAtiZW5lZmljaWFyeQAAAAATAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAAOZ2V0X21pbGVzdG9uZXMAAAAAAAAAAAABAAAD6gAAB9AAAAAJTWlsZXN0b25lAAAA",
+            "AAAAAAAAAAAAAAASZ2V0X25leHRfbWlsZXN0b25lAAAAAAAAAAAAAQAAAAQ=",
+            "AAAAAAAAAAAAAAAIcmFnZXF1aXQAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABnNoYXJlcwAAAAAACwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAPY2FuY2VsX3Byb3Bvc2FsAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAA=",
+            "AAAAAAAAAAAAAAARc2V0X2ludmVzdG9yX2dhdGUAAAAAAAABAAAAAAAAAARnYXRlAAAH0AAAAAxJbnZlc3RvckdhdGUAAAAA",
+            "AAAAAAAAAAAAAAARZ2V0X2ludmVzdG9yX2dhdGUAAAAAAAAAAAAAAQAAB9AAAAAMSW52ZXN0b3JHYXRl",
+            "AAAAAAAAAAAAAAAOYWxsb3dfaW52ZXN0b3IAAAAAAAEAAAAAAAAACGludmVzdG9yAAAAEwAAAAA=",
+            "AAAAAAAAAAAAAAARZGlzYWxsb3dfaW52ZXN0b3IAAAAAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAA",
+            "AAAAAAAAAAAAAAATaXNfaW52ZXN0b3JfYWxsb3dlZAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+            "AAAAAAAAAAAAAAAbc2V0X3NoYXJlX3RyYW5zZmVyc19lbmFibGVkAAAAAAEAAAAAAAAAB2VuYWJsZWQAAAAAAQAAAAA=",
+            "AAAAAAAAAAAAAAANZnJlZXplX3NoYXJlcwAAAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+            "AAAAAAAAAAAAAAAPdW5mcmVlemVfc2hhcmVzAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+            "AAAAAAAAAAAAAAAPY2xhd2JhY2tfc2hhcmVzAAAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAAAAAAAMc2V0X2d1YXJkaWFuAAAAAQAAAAAAAAAMbmV3X2d1YXJkaWFuAAAD6AAAABMAAAAA",
+            "AAAAAAAAAAAAAAAMZ2V0X2d1YXJkaWFuAAAAAAAAAAEAAAPoAAAAEw==",
+            "AAAAAAAAAAAAAAATcmVjb3JkX2Rpc3RyaWJ1dGlvbgAAAAACAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAAAAAAAWZ2V0X2Rpc3RyaWJ1dGlvbl9jb3VudAAAAAAAAAAAAAEAAAAE",
+            "AAAAAAAAAAAAAAAYZ2V0X2Rpc3RyaWJ1dGlvbl9oaXN0b3J5AAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAA+0AAAACAAAABgAAAAs=",
             "AAAAAAAAAAAAAAATZ2V0X3Byb3Bvc2Fsc19jb3VudAAAAAAAAAAAAQAAAAY=",
-            "AAAAAAAAAAAAAAAUZ2V0X3Byb3Bvc2FsX2RldGFpbHMAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAABE=",
+            "AAAAAAAAAAAAAAAMZ2V0X3Byb3Bvc2FsAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAfQAAAACFByb3Bvc2Fs",
+            "AAAAAAAAAAAAAAANZ2V0X3Byb3Bvc2FscwAAAAAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAGAAAAAQAAA+oAAAfQAAAACFByb3Bvc2Fs",
+            "AAAAAAAAAAAAAAAMZ2V0X3Byb3Bvc2VyAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAT",
+            "AAAAAAAAAAAAAAAUZ2V0X3Byb3Bvc2FsX2RldGFpbHMAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAB9AAAAAHQ29udGVudAA=",
             "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3ZvdGVzAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAs=",
             "AAAAAAAAAAAAAAAVZ2V0X3Byb3Bvc2FsX2V4ZWN1dGVkAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAB",
+            "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3N0YXRlAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAA==",
+            "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3RhbGx5AAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAA+0AAAADAAAACwAAAAsAAAAL",
+            "AAAAAAAAAAAAAAAKZ2V0X2JhbGxvdAAAAAAAAgAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAFdm90ZXIAAAAAAAATAAAAAQAAA+gAAAfQAAAABkJhbGxvdAAA",
+            "AAAAAAAAAAAAAAAQZ2V0X3Byb3Bvc2FsX2V0YQAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAABAAAABg==",
+            "AAAAAAAAAAAAAAAVZ2V0X3Byb3Bvc2FsX2RlYWRsaW5lAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAG",
             "AAAAAAAAAAAAAAAQZ2V0X3ZvdGluZ19wb3dlcgAAAAEAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAEAAAAL",
-            "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAABg==",
+            "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAUZ2V0X3RvdGFsX3JlaW52ZXN0ZWQAAAAAAAAAAQAAAAs=",
             "AAAAAAAAAAAAAAAXaXNfZnVuZGluZ19nb2FsX3JlYWNoZWQAAAAAAAAAAAEAAAAB",
+            "AAAAAAAAAAAAAAAPZ2V0X3JhaXNlX3Rlcm1zAAAAAAAAAAABAAAH0AAAAApSYWlzZVRlcm1zAAA=",
+            "AAAAAAAAAAAAAAAKZ2V0X3N0YXR1cwAAAAAAAAAAAAEAAAfQAAAACURhb1N0YXR1cwAAAA==",
             "AAAAAAAAAAAAAAANZ2V0X2ludmVzdG9ycwAAAAAAAAAAAAABAAAD6gAAABM=",
             "AAAAAAAAAAAAAAASZ2V0X3Rva2VuX2NvbnRyYWN0AAAAAAAAAAAAAQAAABM=",
-            "AAAAAAAAAAAAAAAPZ2V0X2ludmVzdG1lbnRzAAAAAAAAAAABAAAD7AAAABMAAAAG",
-            "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABE=",
-            "AAAAAAAAAAAAAAAPZ2V0X2Rlc2NyaXB0aW9uAAAAAAAAAAABAAAAEQ==",
-            "AAAAAAAAAAAAAAAQZ2V0X2Z1bmRpbmdfZ29hbAAAAAAAAAABAAAABg==",
-            "AAAAAAAAAAAAAAALZ2V0X2NyZWF0b3IAAAAAAAAAAAEAAAAT",
-            "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9wdGMAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-            "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-            "AAAAAAAAAAAAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
-            "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-            "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
-            "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAN3aG8AAAAAEwAAAAEAAAAL",
-            "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL"]), options);
+            "AAAAAAAAAAAAAAAPZ2V0X2ludmVzdG1lbnRzAAAAAAAAAAABAAAD7AAAABMAAAAL",
+            "AAAAAAAAAAAAAAAIZ2V0X2luZm8AAAAAAAAAAQAAB9AAAAAHQ29udGVudAA=",
+            "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABA=",
+            "AAAAAAAAAAAAAAAPZ2V0X2Rlc2NyaXB0aW9uAAAAAAAAAAABAAAH0AAAAAtDb250ZW50Qm9keQA=",
+            "AAAAAAAAAAAAAAAQZ2V0X2Z1bmRpbmdfZ29hbAAAAAAAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAAAAAAEAAAfQAAAACURhb0NvbmZpZwAAAA==",
+            "AAAAAAAAAAAAAAAUZ2V0X2Z1bmRpbmdfZGVhZGxpbmUAAAAAAAAAAQAAAAY=",
+            "AAAAAAAAAAAAAAALZ2V0X2ZhY3RvcnkAAAAAAAAAAAEAAAAT",
+            "AAAAAAAAAAAAAAALZ2V0X2NyZWF0b3IAAAAAAAAAAAEAAAAT"]), options);
         this.options = options;
     }
     fromJSON = {
         initialize_dao: (this.txFromJSON),
         invest: (this.txFromJSON),
+        reinvest_profit: (this.txFromJSON),
         create_proposal: (this.txFromJSON),
         vote: (this.txFromJSON),
+        queue: (this.txFromJSON),
         execute_proposal: (this.txFromJSON),
+        claim_refund: (this.txFromJSON),
+        claim_vested: (this.txFromJSON),
+        get_vesting_schedules: (this.txFromJSON),
+        get_claimable_vested: (this.txFromJSON),
+        get_milestones: (this.txFromJSON),
+        get_next_milestone: (this.txFromJSON),
+        ragequit: (this.txFromJSON),
+        cancel_proposal: (this.txFromJSON),
+        set_investor_gate: (this.txFromJSON),
+        get_investor_gate: (this.txFromJSON),
+        allow_investor: (this.txFromJSON),
+        disallow_investor: (this.txFromJSON),
+        is_investor_allowed: (this.txFromJSON),
+        set_share_transfers_enabled: (this.txFromJSON),
+        freeze_shares: (this.txFromJSON),
+        unfreeze_shares: (this.txFromJSON),
+        clawback_shares: (this.txFromJSON),
+        set_guardian: (this.txFromJSON),
+        get_guardian: (this.txFromJSON),
         record_distribution: (this.txFromJSON),
+        get_distribution_count: (this.txFromJSON),
         get_distribution_history: (this.txFromJSON),
         get_proposals_count: (this.txFromJSON),
+        get_proposal: (this.txFromJSON),
+        get_proposals: (this.txFromJSON),
+        get_proposer: (this.txFromJSON),
         get_proposal_details: (this.txFromJSON),
         get_proposal_votes: (this.txFromJSON),
         get_proposal_executed: (this.txFromJSON),
+        get_proposal_state: (this.txFromJSON),
+        get_proposal_tally: (this.txFromJSON),
+        get_ballot: (this.txFromJSON),
+        get_proposal_eta: (this.txFromJSON),
+        get_proposal_deadline: (this.txFromJSON),
         get_voting_power: (this.txFromJSON),
         get_total_raised: (this.txFromJSON),
+        get_total_reinvested: (this.txFromJSON),
         is_funding_goal_reached: (this.txFromJSON),
+        get_raise_terms: (this.txFromJSON),
+        get_status: (this.txFromJSON),
         get_investors: (this.txFromJSON),
         get_token_contract: (this.txFromJSON),
         get_investments: (this.txFromJSON),
+        get_info: (this.txFromJSON),
         get_name: (this.txFromJSON),
         get_description: (this.txFromJSON),
         get_funding_goal: (this.txFromJSON),
-        get_creator: (this.txFromJSON),
-        initialize_ptc: (this.txFromJSON),
-        get_admin: (this.txFromJSON),
-        mint: (this.txFromJSON),
-        burn: (this.txFromJSON),
-        transfer: (this.txFromJSON),
-        approve: (this.txFromJSON),
-        allowance: (this.txFromJSON),
-        transfer_from: (this.txFromJSON),
-        balance: (this.txFromJSON),
-        total_supply: (this.txFromJSON)
+        get_config: (this.txFromJSON),
+        get_funding_deadline: (this.txFromJSON),
+        get_factory: (this.txFromJSON),
+        get_creator: (this.txFromJSON)
     };
 }
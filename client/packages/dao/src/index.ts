@@ -38,15 +38,467 @@ export const networks = {
   }
 } as const
 
+
+export type DaoStatus = {tag: "Fundraising", values: void} | {tag: "Funded", values: void} | {tag: "Refunding", values: void};
+
+export interface Milestone {
+  amount: i128;
+  description: string;
+  due_date: u64;
+}
+
+export interface DaoConfig {
+  accepted_asset: string;
+  approval_threshold_bps: u32;
+  compliance_enabled: boolean;
+  funding_period: u64;
+  grace_period: u64;
+  hard_cap: i128;
+  lockup_period: u64;
+  max_active_proposals: u32;
+  max_investment: i128;
+  min_investment: i128;
+  profit_distributor: string;
+  proposal_threshold: i128;
+  quorum_bps: u32;
+  timelock_delay: u64;
+  token_name: string;
+  token_symbol: string;
+  voting_delay: u64;
+  voting_period: u64;
+}
+
+export interface RaiseTerms {
+  accepted_asset: string;
+  funding_deadline: u64;
+  funding_goal: i128;
+  hard_cap: i128;
+  max_investment: i128;
+  min_investment: i128;
+  total_raised: i128;
+}
+
+export type ContentBody = {tag: "None", values: void} | {tag: "Inline", values: readonly [Buffer]} | {tag: "Uri", values: readonly [string]};
+
+export interface Content {
+  body: ContentBody;
+  content_hash: Buffer;
+  title: string;
+}
+
+export type ProposalStatus = {tag: "Pending", values: void} | {tag: "Active", values: void} | {tag: "Defeated", values: void} | {tag: "Succeeded", values: void} | {tag: "Queued", values: void} | {tag: "Executed", values: void} | {tag: "Cancelled", values: void} | {tag: "Expired", values: void};
+
+export type VoteChoice = {tag: "For", values: void} | {tag: "Against", values: void} | {tag: "Abstain", values: void};
+
+export interface Ballot {
+  choice: VoteChoice;
+  reason: string;
+  weight: i128;
+}
+
+export interface VestingGrant {
+  amount: i128;
+  beneficiary: string;
+  cliff: u64;
+  duration: u64;
+}
+
+export interface VestingSchedule {
+  claimed: i128;
+  grant: VestingGrant;
+  start: u64;
+}
+
+export type ProposalAction = {tag: "None", values: void} | {tag: "Payout", values: readonly [string, i128]} | {tag: "ReleaseMilestone", values: readonly [u32]} | {tag: "Refund", values: void} | {tag: "GrantVesting", values: readonly [VestingGrant]} | {tag: "MintShares", values: readonly [Array<readonly [string, i128]>]} | {tag: "SetShareMaxSupply", values: readonly [i128]} | {tag: "RemoveShareMaxSupply", values: void} | {tag: "SetShareAdmin", values: readonly [string]};
+
+export interface Proposal {
+  action: ProposalAction;
+  content: Content;
+  deadline: u64;
+  eta: u64;
+  id: u64;
+  proposer: string;
+  start: u64;
+  status: ProposalStatus;
+  votes_abstain: i128;
+  votes_against: i128;
+  votes_for: i128;
+}
+
+export type DataKey = {tag: "Proposal", values: readonly [u64]} | {tag: "Ballot", values: readonly [u64, string]} | {tag: "ActiveProposals", values: readonly [string]} | {tag: "Allowed", values: readonly [string]} | {tag: "Distribution", values: readonly [u32]};
+
+export type InvestorGate = {tag: "Open", values: void} | {tag: "Allowlist", values: void} | {tag: "Attestation", values: readonly [string]};
+
 export const Errors = {
+  1: {message:"Overflow"},
+  2: {message:"DivisionByZero"}
+}
+
+export interface Client {
+  /**
+   * Construct and simulate a initialize_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  initialize_dao: ({info, funding_goal, creator, token_contract_id, config, milestones}: {info: Content, funding_goal: i128, creator: string, token_contract_id: string, config: DaoConfig, milestones: Array<Milestone>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a invest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  invest: ({investor, amount}: {investor: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a reinvest_profit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  reinvest_profit: ({investor, amount}: {investor: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a create_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  create_proposal: ({proposer, content, action}: {proposer: string, content: Content, action: ProposalAction}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  vote: ({voter, proposal_id, choice, reason}: {voter: string, proposal_id: u64, choice: VoteChoice, reason: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a queue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  queue: ({proposal_id}: {proposal_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a execute_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  execute_proposal: ({proposal_id}: {proposal_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a claim_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_refund: ({investor}: {investor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a claim_vested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_vested: ({beneficiary}: {beneficiary: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_vesting_schedules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_vesting_schedules: ({beneficiary}: {beneficiary: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<VestingSchedule>>>
+
+  /**
+   * Construct and simulate a get_claimable_vested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_claimable_vested: ({beneficiary}: {beneficiary: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_milestones transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_milestones: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Milestone>>>
+
+  /**
+   * Construct and simulate a get_next_milestone transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_next_milestone: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a ragequit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  ragequit: ({investor, shares}: {investor: string, shares: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a cancel_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_proposal: ({caller, proposal_id}: {caller: string, proposal_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_investor_gate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_investor_gate: ({gate}: {gate: InvestorGate}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_investor_gate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_investor_gate: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<InvestorGate>>
 
-}
+  /**
+   * Construct and simulate a allow_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  allow_investor: ({investor}: {investor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
 
-export interface Client {
   /**
-   * Construct and simulate a initialize_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a disallow_investor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize_dao: ({name, description, funding_goal, creator, token_contract_id}: {name: string, description: string, funding_goal: u64, creator: string, token_contract_id: string}, options?: {
+  disallow_investor: ({investor}: {investor: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -64,9 +516,29 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a invest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a is_investor_allowed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_investor_allowed: ({account}: {account: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_share_transfers_enabled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  invest: ({investor, amount}: {investor: string, amount: u64}, options?: {
+  set_share_transfers_enabled: ({enabled}: {enabled: boolean}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -84,9 +556,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a create_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a freeze_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  create_proposal: ({details}: {details: string}, options?: {
+  freeze_shares: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -101,12 +573,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a unfreeze_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  vote: ({voter, proposal_id, support}: {voter: string, proposal_id: u64, support: boolean}, options?: {
+  unfreeze_shares: ({account}: {account: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -124,9 +596,29 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a execute_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a clawback_shares transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  execute_proposal: ({proposal_id}: {proposal_id: u64}, options?: {
+  clawback_shares: ({from, amount}: {from: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_guardian: ({new_guardian}: {new_guardian: Option<string>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -143,10 +635,30 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_guardian: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
   /**
    * Construct and simulate a record_distribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  record_distribution: ({timestamp, amount}: {timestamp: u64, amount: u64}, options?: {
+  record_distribution: ({timestamp, amount}: {timestamp: u64, amount: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -163,10 +675,30 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_distribution_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_distribution_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
   /**
    * Construct and simulate a get_distribution_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_distribution_history: (options?: {
+  get_distribution_history: ({start, limit}: {start: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -181,7 +713,7 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<readonly [u64, u64]>>>
+  }) => Promise<AssembledTransaction<Array<readonly [u64, i128]>>>
 
   /**
    * Construct and simulate a get_proposals_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -203,6 +735,66 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<u64>>
 
+  /**
+   * Construct and simulate a get_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_proposal: ({proposal_id}: {proposal_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Proposal>>
+
+  /**
+   * Construct and simulate a get_proposals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_proposals: ({start, limit}: {start: u64, limit: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Proposal>>>
+
+  /**
+   * Construct and simulate a get_proposer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_proposer: ({proposal_id}: {proposal_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
   /**
    * Construct and simulate a get_proposal_details transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -221,7 +813,7 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Content>>
 
   /**
    * Construct and simulate a get_proposal_votes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -264,9 +856,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a get_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_proposal_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_voting_power: ({voter}: {voter: string}, options?: {
+  get_proposal_state: ({proposal_id}: {proposal_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -281,12 +873,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<ProposalStatus>>
 
   /**
-   * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_proposal_tally transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_total_raised: (options?: {
+  get_proposal_tally: ({proposal_id}: {proposal_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -301,12 +893,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<readonly [i128, i128, i128]>>
 
   /**
-   * Construct and simulate a is_funding_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_ballot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  is_funding_goal_reached: (options?: {
+  get_ballot: ({proposal_id, voter}: {proposal_id: u64, voter: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -321,12 +913,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Option<Ballot>>>
 
   /**
-   * Construct and simulate a get_investors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_proposal_eta transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_investors: (options?: {
+  get_proposal_eta: ({proposal_id}: {proposal_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -341,12 +933,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<string>>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_proposal_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_token_contract: (options?: {
+  get_proposal_deadline: ({proposal_id}: {proposal_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -361,12 +953,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a get_investments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_investments: (options?: {
+  get_voting_power: ({voter}: {voter: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -381,12 +973,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Map<string, u64>>>
+  }) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_name: (options?: {
+  get_total_raised: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -401,12 +993,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a get_description transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_total_reinvested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_description: (options?: {
+  get_total_reinvested: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -421,12 +1013,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a get_funding_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a is_funding_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_funding_goal: (options?: {
+  is_funding_goal_reached: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -441,12 +1033,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a get_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_raise_terms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_creator: (options?: {
+  get_raise_terms: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -461,12 +1053,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<RaiseTerms>>
 
   /**
-   * Construct and simulate a initialize_ptc transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize_ptc: ({admin}: {admin: string}, options?: {
+  get_status: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -481,12 +1073,32 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<DaoStatus>>
+
+  /**
+   * Construct and simulate a get_investors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_investors: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_admin: (options?: {
+  get_token_contract: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -504,9 +1116,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_investments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  mint: ({to, amount}: {to: string, amount: i128}, options?: {
+  get_investments: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -521,12 +1133,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Map<string, i128>>>
 
   /**
-   * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  burn: ({from, amount}: {from: string, amount: i128}, options?: {
+  get_info: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -541,12 +1153,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Content>>
 
   /**
-   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer: ({from, to, amount}: {from: string, to: string, amount: i128}, options?: {
+  get_name: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -561,12 +1173,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_description transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  approve: ({owner, spender, amount}: {owner: string, spender: string, amount: i128}, options?: {
+  get_description: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -581,12 +1193,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<ContentBody>>
 
   /**
-   * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_funding_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  allowance: ({owner, spender}: {owner: string, spender: string}, options?: {
+  get_funding_goal: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -604,9 +1216,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<i128>>
 
   /**
-   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer_from: ({spender, from, to, amount}: {spender: string, from: string, to: string, amount: i128}, options?: {
+  get_config: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -621,12 +1233,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<DaoConfig>>
 
   /**
-   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_funding_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  balance: ({who}: {who: string}, options?: {
+  get_funding_deadline: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -641,12 +1253,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_factory transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  total_supply: (options?: {
+  get_factory: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -661,11 +1273,33 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_creator: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {factory}: {factory: string},
     /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -677,75 +1311,146 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options)
+    return ContractClient.deploy({factory}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9kYW8AAAAAAAUAAAAAAAAABG5hbWUAAAARAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAARAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAAGAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEXRva2VuX2NvbnRyYWN0X2lkAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAGaW52ZXN0AAAAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABmFtb3VudAAAAAAABgAAAAA=",
-        "AAAAAAAAAAAAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAEAAAAAAAAAB2RldGFpbHMAAAAAEQAAAAEAAAAG",
-        "AAAAAAAAAAAAAAAEdm90ZQAAAAMAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAHc3VwcG9ydAAAAAABAAAAAA==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAAgAAAAAAAAAIT3ZlcmZsb3cAAAABAAAAAAAAAA5EaXZpc2lvbkJ5WmVybwAAAAAAAg==",
+        "AAAAAgAAAAAAAAAAAAAACURhb1N0YXR1cwAAAAAAAAMAAAAAAAAAAAAAAAtGdW5kcmFpc2luZwAAAAAAAAAAAAAAAAZGdW5kZWQAAAAAAAAAAAAAAAAACVJlZnVuZGluZwAAAA==",
+        "AAAAAQAAAAAAAAAAAAAACU1pbGVzdG9uZQAAAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAIZHVlX2RhdGUAAAAG",
+        "AAAAAQAAAAAAAAAAAAAACURhb0NvbmZpZwAAAAAAABIAAAAAAAAADmFjY2VwdGVkX2Fzc2V0AAAAAAATAAAAAAAAABZhcHByb3ZhbF90aHJlc2hvbGRfYnBzAAAAAAAEAAAAAAAAABJjb21wbGlhbmNlX2VuYWJsZWQAAAAAAAEAAAAAAAAADmZ1bmRpbmdfcGVyaW9kAAAAAAAGAAAAAAAAAAxncmFjZV9wZXJpb2QAAAAGAAAAAAAAAAhoYXJkX2NhcAAAAAsAAAAAAAAADWxvY2t1cF9wZXJpb2QAAAAAAAAGAAAAAAAAABRtYXhfYWN0aXZlX3Byb3Bvc2FscwAAAAQAAAAAAAAADm1heF9pbnZlc3RtZW50AAAAAAALAAAAAAAAAA5taW5faW52ZXN0bWVudAAAAAAACwAAAAAAAAAScHJvZml0X2Rpc3RyaWJ1dG9yAAAAAAATAAAAAAAAABJwcm9wb3NhbF90aHJlc2hvbGQAAAAAAAsAAAAAAAAACnF1b3J1bV9icHMAAAAAAAQAAAAAAAAADnRpbWVsb2NrX2RlbGF5AAAAAAAGAAAAAAAAAAp0b2tlbl9uYW1lAAAAAAAQAAAAAAAAAAx0b2tlbl9zeW1ib2wAAAAQAAAAAAAAAAx2b3RpbmdfZGVsYXkAAAAGAAAAAAAAAA12b3RpbmdfcGVyaW9kAAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAAClJhaXNlVGVybXMAAAAAAAcAAAAAAAAADmFjY2VwdGVkX2Fzc2V0AAAAAAATAAAAAAAAABBmdW5kaW5nX2RlYWRsaW5lAAAABgAAAAAAAAAMZnVuZGluZ19nb2FsAAAACwAAAAAAAAAIaGFyZF9jYXAAAAALAAAAAAAAAA5tYXhfaW52ZXN0bWVudAAAAAAACwAAAAAAAAAObWluX2ludmVzdG1lbnQAAAAAAAsAAAAAAAAADHRvdGFsX3JhaXNlZAAAAAs=",
+        "AAAAAgAAAAAAAAAAAAAAC0NvbnRlbnRCb2R5AAAAAAMAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAGSW5saW5lAAAAAAABAAAADgAAAAEAAAAAAAAAA1VyaQAAAAABAAAAEA==",
+        "AAAAAQAAAAAAAAAAAAAAB0NvbnRlbnQAAAAAAwAAAAAAAAAEYm9keQAAB9AAAAALQ29udGVudEJvZHkAAAAAAAAAAAxjb250ZW50X2hhc2gAAAPuAAAAIAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+        "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsU3RhdHVzAAAAAAAIAAAAAAAAAAAAAAAHUGVuZGluZwAAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAAAAAAAAAAACERlZmVhdGVkAAAAAAAAAAAAAAAJU3VjY2VlZGVkAAAAAAAAAAAAAAAAAAAGUXVldWVkAAAAAAAAAAAAAAAAAAhFeGVjdXRlZAAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAAAAAAAAAAAAAAAAB0V4cGlyZWQA",
+        "AAAAAgAAAAAAAAAAAAAAClZvdGVDaG9pY2UAAAAAAAMAAAAAAAAAAAAAAANGb3IAAAAAAAAAAAAAAAAHQWdhaW5zdAAAAAAAAAAAAAAAAAdBYnN0YWluAA==",
+        "AAAAAQAAAAAAAAAAAAAABkJhbGxvdAAAAAAAAwAAAAAAAAAGY2hvaWNlAAAAAAfQAAAAClZvdGVDaG9pY2UAAAAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAAAAAAZ3ZWlnaHQAAAAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAADFZlc3RpbmdHcmFudAAAAAQAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAAAAAAFY2xpZmYAAAAAAAAGAAAAAAAAAAhkdXJhdGlvbgAAAAY=",
+        "AAAAAQAAAAAAAAAAAAAAD1Zlc3RpbmdTY2hlZHVsZQAAAAADAAAAAAAAAAdjbGFpbWVkAAAAAAsAAAAAAAAABWdyYW50AAAAAAAH0AAAAAxWZXN0aW5nR3JhbnQAAAAAAAAABXN0YXJ0AAAAAAAABg==",
+        "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAJAAAAAAAAAAAAAAAETm9uZQAAAAEAAAAAAAAABlBheW91dAAAAAAAAgAAABMAAAALAAAAAQAAAAAAAAAQUmVsZWFzZU1pbGVzdG9uZQAAAAEAAAAEAAAAAAAAAAAAAAAGUmVmdW5kAAAAAAABAAAAAAAAAAxHcmFudFZlc3RpbmcAAAABAAAH0AAAAAxWZXN0aW5nR3JhbnQAAAABAAAAAAAAAApNaW50U2hhcmVzAAAAAAABAAAD6gAAA+0AAAACAAAAEwAAAAsAAAABAAAAAAAAABFTZXRTaGFyZU1heFN1cHBseQAAAAAAAAEAAAALAAAAAAAAAAAAAAAUUmVtb3ZlU2hhcmVNYXhTdXBwbHkAAAABAAAAAAAAAA1TZXRTaGFyZUFkbWluAAAAAAAAAQAAABM=",
+        "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAACwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAAAAAAB2NvbnRlbnQAAAAH0AAAAAdDb250ZW50AAAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAANldGEAAAAABgAAAAAAAAACaWQAAAAAAAYAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAFc3RhcnQAAAAAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAAAAAAAAAAANdm90ZXNfYWJzdGFpbgAAAAAAAAsAAAAAAAAADXZvdGVzX2FnYWluc3QAAAAAAAALAAAAAAAAAAl2b3Rlc19mb3IAAAAAAAAL",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAEAAAAAAAAACFByb3Bvc2FsAAAAAQAAAAYAAAABAAAAAAAAAAZCYWxsb3QAAAAAAAIAAAAGAAAAEwAAAAEAAAAAAAAAD0FjdGl2ZVByb3Bvc2FscwAAAAABAAAAEwAAAAEAAAAAAAAAB0FsbG93ZWQAAAAAAQAAABMAAAABAAAAAAAAAAxEaXN0cmlidXRpb24AAAABAAAABA==",
+        "AAAAAgAAAAAAAAAAAAAADEludmVzdG9yR2F0ZQAAAAMAAAAAAAAAAAAAAARPcGVuAAAAAAAAAAAAAAAJQWxsb3dsaXN0AAAAAAAAAQAAAAAAAAALQXR0ZXN0YXRpb24AAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAAB2ZhY3RvcnkAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9kYW8AAAAAAAYAAAAAAAAABGluZm8AAAfQAAAAB0NvbnRlbnQAAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAALAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEXRva2VuX2NvbnRyYWN0X2lkAAAAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAACURhb0NvbmZpZwAAAAAAAAAAAAAKbWlsZXN0b25lcwAAAAAD6gAAB9AAAAAJTWlsZXN0b25lAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAGaW52ZXN0AAAAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAPcmVpbnZlc3RfcHJvZml0AAAAAAIAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAAAAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAMAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAHY29udGVudAAAAAfQAAAAB0NvbnRlbnQAAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAAOUHJvcG9zYWxBY3Rpb24AAAAAAAEAAAAG",
+        "AAAAAAAAAAAAAAAEdm90ZQAAAAQAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAGY2hvaWNlAAAAAAfQAAAAClZvdGVDaG9pY2UAAAAAAAAAAAAGcmVhc29uAAAAAAPoAAAAEAAAAAA=",
+        "AAAAAAAAAAAAAAAFcXVldWUAAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAY=",
         "AAAAAAAAAAAAAAAQZXhlY3V0ZV9wcm9wb3NhbAAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAAA",
-        "AAAAAAAAAAAAAAATcmVjb3JkX2Rpc3RyaWJ1dGlvbgAAAAACAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAYAAAAA",
-        "AAAAAAAAAAAAAAAYZ2V0X2Rpc3RyaWJ1dGlvbl9oaXN0b3J5AAAAAAAAAAEAAAPqAAAD7QAAAAIAAAAGAAAABg==",
+        "AAAAAAAAAAAAAAAMY2xhaW1fcmVmdW5kAAAAAQAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAMY2xhaW1fdmVzdGVkAAAAAQAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAVZ2V0X3Zlc3Rpbmdfc2NoZWR1bGVzAAAAAAAAAQAAAAAAAAALYmVuZWZpY2lhcnkAAAAAEwAAAAEAAAPqAAAH0AAAAA9WZXN0aW5nU2NoZWR1bGUA",
+        "AAAAAAAAAAAAAAAUZ2V0X2NsYWltYWJsZV92ZXN0ZWQAAAABAAAAAAAAAAtiZW5lZmljaWFyeQAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAOZ2V0X21pbGVzdG9uZXMAAAAAAAAAAAABAAAD6gAAB9AAAAAJTWlsZXN0b25lAAAA",
+        "AAAAAAAAAAAAAAASZ2V0X25leHRfbWlsZXN0b25lAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAIcmFnZXF1aXQAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABnNoYXJlcwAAAAAACwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAPY2FuY2VsX3Byb3Bvc2FsAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAAAAAAARc2V0X2ludmVzdG9yX2dhdGUAAAAAAAABAAAAAAAAAARnYXRlAAAH0AAAAAxJbnZlc3RvckdhdGUAAAAA",
+        "AAAAAAAAAAAAAAARZ2V0X2ludmVzdG9yX2dhdGUAAAAAAAAAAAAAAQAAB9AAAAAMSW52ZXN0b3JHYXRl",
+        "AAAAAAAAAAAAAAAOYWxsb3dfaW52ZXN0b3IAAAAAAAEAAAAAAAAACGludmVzdG9yAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAARZGlzYWxsb3dfaW52ZXN0b3IAAAAAAAABAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAA",
+        "AAAAAAAAAAAAAAATaXNfaW52ZXN0b3JfYWxsb3dlZAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAAAAAAAbc2V0X3NoYXJlX3RyYW5zZmVyc19lbmFibGVkAAAAAAEAAAAAAAAAB2VuYWJsZWQAAAAAAQAAAAA=",
+        "AAAAAAAAAAAAAAANZnJlZXplX3NoYXJlcwAAAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAPdW5mcmVlemVfc2hhcmVzAAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAPY2xhd2JhY2tfc2hhcmVzAAAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAMc2V0X2d1YXJkaWFuAAAAAQAAAAAAAAAMbmV3X2d1YXJkaWFuAAAD6AAAABMAAAAA",
+        "AAAAAAAAAAAAAAAMZ2V0X2d1YXJkaWFuAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAAAAAAATcmVjb3JkX2Rpc3RyaWJ1dGlvbgAAAAACAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAWZ2V0X2Rpc3RyaWJ1dGlvbl9jb3VudAAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAYZ2V0X2Rpc3RyaWJ1dGlvbl9oaXN0b3J5AAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAA+0AAAACAAAABgAAAAs=",
         "AAAAAAAAAAAAAAATZ2V0X3Byb3Bvc2Fsc19jb3VudAAAAAAAAAAAAQAAAAY=",
-        "AAAAAAAAAAAAAAAUZ2V0X3Byb3Bvc2FsX2RldGFpbHMAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAABE=",
+        "AAAAAAAAAAAAAAAMZ2V0X3Byb3Bvc2FsAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAfQAAAACFByb3Bvc2Fs",
+        "AAAAAAAAAAAAAAANZ2V0X3Byb3Bvc2FscwAAAAAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAGAAAAAQAAA+oAAAfQAAAACFByb3Bvc2Fs",
+        "AAAAAAAAAAAAAAAMZ2V0X3Byb3Bvc2VyAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAUZ2V0X3Byb3Bvc2FsX2RldGFpbHMAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAB9AAAAAHQ29udGVudAA=",
         "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3ZvdGVzAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAs=",
         "AAAAAAAAAAAAAAAVZ2V0X3Byb3Bvc2FsX2V4ZWN1dGVkAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAB",
+        "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3N0YXRlAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAA==",
+        "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3RhbGx5AAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAA+0AAAADAAAACwAAAAsAAAAL",
+        "AAAAAAAAAAAAAAAKZ2V0X2JhbGxvdAAAAAAAAgAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAFdm90ZXIAAAAAAAATAAAAAQAAA+gAAAfQAAAABkJhbGxvdAAA",
+        "AAAAAAAAAAAAAAAQZ2V0X3Byb3Bvc2FsX2V0YQAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAVZ2V0X3Byb3Bvc2FsX2RlYWRsaW5lAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAG",
         "AAAAAAAAAAAAAAAQZ2V0X3ZvdGluZ19wb3dlcgAAAAEAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAEAAAAL",
-        "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAUZ2V0X3RvdGFsX3JlaW52ZXN0ZWQAAAAAAAAAAQAAAAs=",
         "AAAAAAAAAAAAAAAXaXNfZnVuZGluZ19nb2FsX3JlYWNoZWQAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAPZ2V0X3JhaXNlX3Rlcm1zAAAAAAAAAAABAAAH0AAAAApSYWlzZVRlcm1zAAA=",
+        "AAAAAAAAAAAAAAAKZ2V0X3N0YXR1cwAAAAAAAAAAAAEAAAfQAAAACURhb1N0YXR1cwAAAA==",
         "AAAAAAAAAAAAAAANZ2V0X2ludmVzdG9ycwAAAAAAAAAAAAABAAAD6gAAABM=",
         "AAAAAAAAAAAAAAASZ2V0X3Rva2VuX2NvbnRyYWN0AAAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAPZ2V0X2ludmVzdG1lbnRzAAAAAAAAAAABAAAD7AAAABMAAAAG",
-        "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABE=",
-        "AAAAAAAAAAAAAAAPZ2V0X2Rlc2NyaXB0aW9uAAAAAAAAAAABAAAAEQ==",
-        "AAAAAAAAAAAAAAAQZ2V0X2Z1bmRpbmdfZ29hbAAAAAAAAAABAAAABg==",
-        "AAAAAAAAAAAAAAALZ2V0X2NyZWF0b3IAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9wdGMAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-        "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
-        "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
-        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-        "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAN3aG8AAAAAEwAAAAEAAAAL",
-        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL" ]),
+        "AAAAAAAAAAAAAAAPZ2V0X2ludmVzdG1lbnRzAAAAAAAAAAABAAAD7AAAABMAAAAL",
+        "AAAAAAAAAAAAAAAIZ2V0X2luZm8AAAAAAAAAAQAAB9AAAAAHQ29udGVudAA=",
+        "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABA=",
+        "AAAAAAAAAAAAAAAPZ2V0X2Rlc2NyaXB0aW9uAAAAAAAAAAABAAAH0AAAAAtDb250ZW50Qm9keQA=",
+        "AAAAAAAAAAAAAAAQZ2V0X2Z1bmRpbmdfZ29hbAAAAAAAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAAAAAAEAAAfQAAAACURhb0NvbmZpZwAAAA==",
+        "AAAAAAAAAAAAAAAUZ2V0X2Z1bmRpbmdfZGVhZGxpbmUAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAALZ2V0X2ZhY3RvcnkAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAALZ2V0X2NyZWF0b3IAAAAAAAAAAAEAAAAT" ]),
       options
     )
   }
   public readonly fromJSON = {
     initialize_dao: this.txFromJSON<null>,
-        invest: this.txFromJSON<null>,
+        invest: this.txFromJSON<i128>,
+        reinvest_profit: this.txFromJSON<null>,
         create_proposal: this.txFromJSON<u64>,
         vote: this.txFromJSON<null>,
+        queue: this.txFromJSON<u64>,
         execute_proposal: this.txFromJSON<null>,
+        claim_refund: this.txFromJSON<i128>,
+        claim_vested: this.txFromJSON<i128>,
+        get_vesting_schedules: this.txFromJSON<Array<VestingSchedule>>,
+        get_claimable_vested: this.txFromJSON<i128>,
+        get_milestones: this.txFromJSON<Array<Milestone>>,
+        get_next_milestone: this.txFromJSON<u32>,
+        ragequit: this.txFromJSON<i128>,
+        cancel_proposal: this.txFromJSON<null>,
+        set_investor_gate: this.txFromJSON<null>,
+        get_investor_gate: this.txFromJSON<InvestorGate>,
+        allow_investor: this.txFromJSON<null>,
+        disallow_investor: this.txFromJSON<null>,
+        is_investor_allowed: this.txFromJSON<boolean>,
+        set_share_transfers_enabled: this.txFromJSON<null>,
+        freeze_shares: this.txFromJSON<null>,
+        unfreeze_shares: this.txFromJSON<null>,
+        clawback_shares: this.txFromJSON<null>,
+        set_guardian: this.txFromJSON<null>,
+        get_guardian: this.txFromJSON<Option<string>>,
         record_distribution: this.txFromJSON<null>,
-        get_distribution_history: this.txFromJSON<Array<readonly [u64, u64]>>,
+        get_distribution_count: this.txFromJSON<u32>,
+        get_distribution_history: this.txFromJSON<Array<readonly [u64, i128]>>,
         get_proposals_count: this.txFromJSON<u64>,
-        get_proposal_details: this.txFromJSON<string>,
+        get_proposal: this.txFromJSON<Proposal>,
+        get_proposals: this.txFromJSON<Array<Proposal>>,
+        get_proposer: this.txFromJSON<string>,
+        get_proposal_details: this.txFromJSON<Content>,
         get_proposal_votes: this.txFromJSON<i128>,
         get_proposal_executed: this.txFromJSON<boolean>,
+        get_proposal_state: this.txFromJSON<ProposalStatus>,
+        get_proposal_tally: this.txFromJSON<readonly [i128, i128, i128]>,
+        get_ballot: this.txFromJSON<Option<Ballot>>,
+        get_proposal_eta: this.txFromJSON<u64>,
+        get_proposal_deadline: this.txFromJSON<u64>,
         get_voting_power: this.txFromJSON<i128>,
-        get_total_raised: this.txFromJSON<u64>,
+        get_total_raised: this.txFromJSON<i128>,
+        get_total_reinvested: this.txFromJSON<i128>,
         is_funding_goal_reached: this.txFromJSON<boolean>,
+        get_raise_terms: this.txFromJSON<RaiseTerms>,
+        get_status: this.txFromJSON<DaoStatus>,
         get_investors: this.txFromJSON<Array<string>>,
         get_token_contract: this.txFromJSON<string>,
-        get_investments: this.txFromJSON<Map<string, u64>>,
+        get_investments: this.txFromJSON<Map<string, i128>>,
+        get_info: this.txFromJSON<Content>,
         get_name: this.txFromJSON<string>,
-        get_description: this.txFromJSON<string>,
-        get_funding_goal: this.txFromJSON<u64>,
-        get_creator: this.txFromJSON<string>,
-        initialize_ptc: this.txFromJSON<null>,
-        get_admin: this.txFromJSON<string>,
-        mint: this.txFromJSON<null>,
-        burn: this.txFromJSON<null>,
-        transfer: this.txFromJSON<null>,
-        approve: this.txFromJSON<null>,
-        allowance: this.txFromJSON<i128>,
-        transfer_from: this.txFromJSON<null>,
-        balance: this.txFromJSON<i128>,
-        total_supply: this.txFromJSON<i128>
+        get_description: this.txFromJSON<ContentBody>,
+        get_funding_goal: this.txFromJSON<i128>,
+        get_config: this.txFromJSON<DaoConfig>,
+        get_funding_deadline: this.txFromJSON<u64>,
+        get_factory: this.txFromJSON<string>,
+        get_creator: this.txFromJSON<string>
   }
 }
\ No newline at end of file
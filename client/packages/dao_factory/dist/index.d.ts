@@ -1,6 +1,6 @@
 import { Buffer } from "buffer";
 import { AssembledTransaction, Client as ContractClient, ClientOptions as ContractClientOptions, MethodOptions } from '@stellar/stellar-sdk/contract';
-import type { u64, i128, Option } from '@stellar/stellar-sdk/contract';
+import type { u32, u64, i128, Option } from '@stellar/stellar-sdk/contract';
 export * from '@stellar/stellar-sdk';
 export * as contract from '@stellar/stellar-sdk/contract';
 export * as rpc from '@stellar/stellar-sdk/rpc';
@@ -10,35 +10,95 @@ export declare const networks: {
         readonly contractId: "CCWFJPN4BQRPYDQULKV5UMOIH2NCGYV2X5C24MS454BOBSOCCMXQL2DY";
     };
 };
+export interface DaoSummary {
+    address: string;
+    created_at: u64;
+    creator: string;
+    funding_goal: i128;
+    id: u64;
+    name: string;
+    status: DaoStatus;
+    total_raised: i128;
+}
+export interface CreationFee {
+    amount: i128;
+    asset: string;
+    recipient: string;
+}
+export type DataKey = {
+    tag: "Dao";
+    values: readonly [u64];
+} | {
+    tag: "DaoId";
+    values: readonly [string];
+} | {
+    tag: "Summary";
+    values: readonly [u64];
+} | {
+    tag: "CreatorDaoCount";
+    values: readonly [string];
+} | {
+    tag: "CreatorDao";
+    values: readonly [string, u32];
+};
+export type DaoStatus = {
+    tag: "Fundraising";
+    values: void;
+} | {
+    tag: "Funded";
+    values: void;
+} | {
+    tag: "Refunding";
+    values: void;
+};
+export interface DaoConfig {
+    accepted_asset: string;
+    approval_threshold_bps: u32;
+    compliance_enabled: boolean;
+    funding_period: u64;
+    grace_period: u64;
+    hard_cap: i128;
+    lockup_period: u64;
+    max_active_proposals: u32;
+    max_investment: i128;
+    min_investment: i128;
+    profit_distributor: string;
+    proposal_threshold: i128;
+    quorum_bps: u32;
+    timelock_delay: u64;
+    token_name: string;
+    token_symbol: string;
+    voting_delay: u64;
+    voting_period: u64;
+}
+export interface Content {
+    body: ContentBody;
+    content_hash: Buffer;
+    title: string;
+}
+export interface Milestone {
+    amount: i128;
+    description: string;
+    due_date: u64;
+}
+export type ContentBody = {
+    tag: "None";
+    values: void;
+} | {
+    tag: "Inline";
+    values: readonly [Buffer];
+} | {
+    tag: "Uri";
+    values: readonly [string];
+};
 export declare const Errors: {};
 export interface Client {
     /**
-     * Construct and simulate a create_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    create_dao: ({ name, description, funding_goal, creator }: {
-        name: string;
-        description: string;
-        funding_goal: u64;
-        creator: string;
-    }, options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<readonly [u64, string]>>;
-    /**
-     * Construct and simulate a get_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a set_wasm_hashes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_dao: ({ dao_id }: {
-        dao_id: u64;
+    set_wasm_hashes: ({ token_wasm_hash, dao_wasm_hash }: {
+        token_wasm_hash: Buffer;
+        dao_wasm_hash: Buffer;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -52,31 +112,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Option<string>>>;
-    /**
-     * Construct and simulate a get_dao_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    get_dao_count: (options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_daos_paginated transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_wasm_hashes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_daos_paginated: ({ start, limit }: {
-        start: u64;
-        limit: u64;
-    }, options?: {
+    get_wasm_hashes: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -89,13 +129,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>;
+    }) => Promise<AssembledTransaction<readonly [Buffer, Buffer]>>;
     /**
-     * Construct and simulate a get_daos_by_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_daos_by_creator: ({ creator }: {
-        creator: string;
-    }, options?: {
+    get_admin: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -108,11 +146,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>;
+    }) => Promise<AssembledTransaction<string>>;
     /**
-     * Construct and simulate a get_all_daos transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_all_daos: (options?: {
+    get_pending_admin: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -125,16 +163,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<string>>>;
+    }) => Promise<AssembledTransaction<Option<string>>>;
     /**
-     * Construct and simulate a initialize_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    initialize_dao: ({ name, description, funding_goal, creator, token_contract_id }: {
-        name: string;
-        description: string;
-        funding_goal: u64;
-        creator: string;
-        token_contract_id: string;
+    propose_admin: ({ new_admin }: {
+        new_admin: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -150,12 +184,9 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a invest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    invest: ({ investor, amount }: {
-        investor: string;
-        amount: u64;
-    }, options?: {
+    accept_admin: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -170,11 +201,9 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a create_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    create_proposal: ({ details }: {
-        details: string;
-    }, options?: {
+    pause: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -187,15 +216,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    vote: ({ voter, proposal_id, support }: {
-        voter: string;
-        proposal_id: u64;
-        support: boolean;
-    }, options?: {
+    unpause: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -210,11 +235,9 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a execute_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    execute_proposal: ({ proposal_id }: {
-        proposal_id: u64;
-    }, options?: {
+    is_paused: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -227,13 +250,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<boolean>>;
     /**
-     * Construct and simulate a record_distribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a set_creation_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    record_distribution: ({ timestamp, amount }: {
-        timestamp: u64;
-        amount: u64;
+    set_creation_fee: ({ asset, amount, recipient }: {
+        asset: string;
+        amount: i128;
+        recipient: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -249,9 +273,9 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_distribution_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a remove_creation_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_distribution_history: (options?: {
+    remove_creation_fee: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -264,30 +288,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<Array<readonly [u64, u64]>>>;
-    /**
-     * Construct and simulate a get_proposals_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    get_proposals_count: (options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_proposal_details transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_creation_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_proposal_details: ({ proposal_id }: {
-        proposal_id: u64;
-    }, options?: {
+    get_creation_fee: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -300,12 +305,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<Option<CreationFee>>>;
     /**
-     * Construct and simulate a get_proposal_votes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a set_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_proposal_votes: ({ proposal_id }: {
-        proposal_id: u64;
+    set_preset: ({ preset, config }: {
+        preset: string;
+        config: DaoConfig;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -319,12 +325,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_proposal_executed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a remove_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_proposal_executed: ({ proposal_id }: {
-        proposal_id: u64;
+    remove_preset: ({ preset }: {
+        preset: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -338,12 +344,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<boolean>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a get_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_voting_power: ({ voter }: {
-        voter: string;
+    get_preset: ({ preset }: {
+        preset: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -357,45 +363,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
-    /**
-     * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    get_total_raised: (options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
-    /**
-     * Construct and simulate a is_funding_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    is_funding_goal_reached: (options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<boolean>>;
+    }) => Promise<AssembledTransaction<Option<DaoConfig>>>;
     /**
-     * Construct and simulate a get_investors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_preset_names transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_investors: (options?: {
+    get_preset_names: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -410,60 +382,15 @@ export interface Client {
         simulate?: boolean;
     }) => Promise<AssembledTransaction<Array<string>>>;
     /**
-     * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    get_token_contract: (options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
-    /**
-     * Construct and simulate a get_investments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a create_dao_from_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_investments: (options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<Map<string, u64>>>;
-    /**
-     * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    get_name: (options?: {
-        /**
-         * The fee to pay for the transaction. Default: BASE_FEE
-         */
-        fee?: number;
-        /**
-         * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-         */
-        timeoutInSeconds?: number;
-        /**
-         * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-         */
-        simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
-    /**
-     * Construct and simulate a get_description transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-     */
-    get_description: (options?: {
+    create_dao_from_preset: ({ info, funding_goal, creator, preset, milestones }: {
+        info: Content;
+        funding_goal: i128;
+        creator: string;
+        preset: string;
+        milestones: Array<Milestone>;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -476,11 +403,17 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<readonly [u64, string]>>;
     /**
-     * Construct and simulate a get_funding_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a create_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_funding_goal: (options?: {
+    create_dao: ({ info, funding_goal, creator, config, milestones }: {
+        info: Content;
+        funding_goal: i128;
+        creator: string;
+        config: DaoConfig;
+        milestones: Array<Milestone>;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -493,11 +426,15 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<u64>>;
+    }) => Promise<AssembledTransaction<readonly [u64, string]>>;
     /**
-     * Construct and simulate a get_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a update_dao_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_creator: (options?: {
+    update_dao_summary: ({ dao, status, total_raised }: {
+        dao: string;
+        status: DaoStatus;
+        total_raised: i128;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -510,12 +447,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<null>>;
     /**
-     * Construct and simulate a initialize_ptc transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_dao_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    initialize_ptc: ({ admin }: {
-        admin: string;
+    get_dao_summary: ({ dao_id }: {
+        dao_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -529,11 +466,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<Option<DaoSummary>>>;
     /**
-     * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_dao_summaries transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_admin: (options?: {
+    get_dao_summaries: ({ start, limit }: {
+        start: u64;
+        limit: u64;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -546,13 +486,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<string>>;
+    }) => Promise<AssembledTransaction<Array<DaoSummary>>>;
     /**
-     * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    mint: ({ to, amount }: {
-        to: string;
-        amount: i128;
+    get_dao: ({ dao_id }: {
+        dao_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -566,13 +505,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<Option<string>>>;
     /**
-     * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_dao_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    burn: ({ from, amount }: {
-        from: string;
-        amount: i128;
+    get_dao_id: ({ dao }: {
+        dao: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -586,14 +524,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<Option<u64>>>;
     /**
-     * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a predict_dao_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    transfer: ({ from, to, amount }: {
-        from: string;
-        to: string;
-        amount: i128;
+    predict_dao_address: ({ dao_id }: {
+        dao_id: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -607,15 +543,11 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<string>>;
     /**
-     * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_dao_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    approve: ({ owner, spender, amount }: {
-        owner: string;
-        spender: string;
-        amount: i128;
-    }, options?: {
+    get_dao_count: (options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -628,13 +560,13 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<u64>>;
     /**
-     * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_daos_paginated transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    allowance: ({ owner, spender }: {
-        owner: string;
-        spender: string;
+    get_daos_paginated: ({ start, limit }: {
+        start: u64;
+        limit: u64;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -648,15 +580,12 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>;
     /**
-     * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_dao_count_by_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    transfer_from: ({ spender, from, to, amount }: {
-        spender: string;
-        from: string;
-        to: string;
-        amount: i128;
+    get_dao_count_by_creator: ({ creator }: {
+        creator: string;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -670,12 +599,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<null>>;
+    }) => Promise<AssembledTransaction<u32>>;
     /**
-     * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a get_daos_by_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    balance: ({ who }: {
-        who: string;
+    get_daos_by_creator: ({ creator, start, limit }: {
+        creator: string;
+        start: u32;
+        limit: u32;
     }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
@@ -689,11 +620,14 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>;
     /**
-     * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Construct and simulate a list_daos transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    total_supply: (options?: {
+    list_daos: ({ cursor, limit }: {
+        cursor: u64;
+        limit: u32;
+    }, options?: {
         /**
          * The fee to pay for the transaction. Default: BASE_FEE
          */
@@ -706,11 +640,17 @@ export interface Client {
          * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
          */
         simulate?: boolean;
-    }) => Promise<AssembledTransaction<i128>>;
+    }) => Promise<AssembledTransaction<readonly [Array<readonly [u64, string, string, DaoStatus]>, Option<u64>]>>;
 }
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
     static deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, token_wasm_hash, dao_wasm_hash }: {
+        admin: string;
+        token_wasm_hash: Buffer;
+        dao_wasm_hash: Buffer;
+    }, 
     /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -722,42 +662,34 @@ export declare class Client extends ContractClient {
     }): Promise<AssembledTransaction<T>>;
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
+        set_wasm_hashes: (json: string) => AssembledTransaction<null>;
+        get_wasm_hashes: (json: string) => AssembledTransaction<readonly [Buffer, Buffer]>;
+        get_admin: (json: string) => AssembledTransaction<string>;
+        get_pending_admin: (json: string) => AssembledTransaction<Option<string>>;
+        propose_admin: (json: string) => AssembledTransaction<null>;
+        accept_admin: (json: string) => AssembledTransaction<null>;
+        pause: (json: string) => AssembledTransaction<null>;
+        unpause: (json: string) => AssembledTransaction<null>;
+        is_paused: (json: string) => AssembledTransaction<boolean>;
+        set_creation_fee: (json: string) => AssembledTransaction<null>;
+        remove_creation_fee: (json: string) => AssembledTransaction<null>;
+        get_creation_fee: (json: string) => AssembledTransaction<Option<CreationFee>>;
+        set_preset: (json: string) => AssembledTransaction<null>;
+        remove_preset: (json: string) => AssembledTransaction<null>;
+        get_preset: (json: string) => AssembledTransaction<Option<DaoConfig>>;
+        get_preset_names: (json: string) => AssembledTransaction<string[]>;
+        create_dao_from_preset: (json: string) => AssembledTransaction<readonly [bigint, string]>;
         create_dao: (json: string) => AssembledTransaction<readonly [bigint, string]>;
+        update_dao_summary: (json: string) => AssembledTransaction<null>;
+        get_dao_summary: (json: string) => AssembledTransaction<Option<DaoSummary>>;
+        get_dao_summaries: (json: string) => AssembledTransaction<DaoSummary[]>;
         get_dao: (json: string) => AssembledTransaction<Option<string>>;
+        get_dao_id: (json: string) => AssembledTransaction<Option<bigint>>;
+        predict_dao_address: (json: string) => AssembledTransaction<string>;
         get_dao_count: (json: string) => AssembledTransaction<bigint>;
         get_daos_paginated: (json: string) => AssembledTransaction<(readonly [bigint, string])[]>;
+        get_dao_count_by_creator: (json: string) => AssembledTransaction<number>;
         get_daos_by_creator: (json: string) => AssembledTransaction<(readonly [bigint, string])[]>;
-        get_all_daos: (json: string) => AssembledTransaction<string[]>;
-        initialize_dao: (json: string) => AssembledTransaction<null>;
-        invest: (json: string) => AssembledTransaction<null>;
-        create_proposal: (json: string) => AssembledTransaction<bigint>;
-        vote: (json: string) => AssembledTransaction<null>;
-        execute_proposal: (json: string) => AssembledTransaction<null>;
-        record_distribution: (json: string) => AssembledTransaction<null>;
-        get_distribution_history: (json: string) => AssembledTransaction<(readonly [bigint, bigint])[]>;
-        get_proposals_count: (json: string) => AssembledTransaction<bigint>;
-        get_proposal_details: (json: string) => AssembledTransaction<string>;
-        get_proposal_votes: (json: string) => AssembledTransaction<bigint>;
-        get_proposal_executed: (json: string) => AssembledTransaction<boolean>;
-        get_voting_power: (json: string) => AssembledTransaction<bigint>;
-        get_total_raised: (json: string) => AssembledTransaction<bigint>;
-        is_funding_goal_reached: (json: string) => AssembledTransaction<boolean>;
-        get_investors: (json: string) => AssembledTransaction<string[]>;
-        get_token_contract: (json: string) => AssembledTransaction<string>;
-        get_investments: (json: string) => AssembledTransaction<Map<string, bigint>>;
-        get_name: (json: string) => AssembledTransaction<string>;
-        get_description: (json: string) => AssembledTransaction<string>;
-        get_funding_goal: (json: string) => AssembledTransaction<bigint>;
-        get_creator: (json: string) => AssembledTransaction<string>;
-        initialize_ptc: (json: string) => AssembledTransaction<null>;
-        get_admin: (json: string) => AssembledTransaction<string>;
-        mint: (json: string) => AssembledTransaction<null>;
-        burn: (json: string) => AssembledTransaction<null>;
-        transfer: (json: string) => AssembledTransaction<null>;
-        approve: (json: string) => AssembledTransaction<null>;
-        allowance: (json: string) => AssembledTransaction<bigint>;
-        transfer_from: (json: string) => AssembledTransaction<null>;
-        balance: (json: string) => AssembledTransaction<bigint>;
-        total_supply: (json: string) => AssembledTransaction<bigint>;
+        list_daos: (json: string) => AssembledTransaction<readonly [(readonly [bigint, string, string, DaoStatus])[], Option<bigint>]>;
     };
 }
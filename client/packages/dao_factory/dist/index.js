@@ -17,87 +17,82 @@ export const Errors = {};
 export class Client extends ContractClient {
     options;
     static async deploy(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, token_wasm_hash, dao_wasm_hash }, 
     /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
     options) {
-        return ContractClient.deploy(null, options);
+        return ContractClient.deploy({ admin, token_wasm_hash, dao_wasm_hash }, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAAAAAAAAAAAKY3JlYXRlX2RhbwAAAAAABAAAAAAAAAAEbmFtZQAAABEAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABEAAAAAAAAADGZ1bmRpbmdfZ29hbAAAAAYAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPtAAAAAgAAAAYAAAAT",
+        super(new ContractSpec(["AAAAAQAAAAAAAAAAAAAACkRhb1N1bW1hcnkAAAAAAAgAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAALAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAlEYW9TdGF0dXMAAAAAAAAAAAAADHRvdGFsX3JhaXNlZAAAAAs=",
+            "AAAAAQAAAAAAAAAAAAAAC0NyZWF0aW9uRmVlAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAlyZWNpcGllbnQAAAAAAAAT",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAEAAAAAAAAAA0RhbwAAAAABAAAABgAAAAEAAAAAAAAABURhb0lkAAAAAAAAAQAAABMAAAABAAAAAAAAAAdTdW1tYXJ5AAAAAAEAAAAGAAAAAQAAAAAAAAAPQ3JlYXRvckRhb0NvdW50AAAAAAEAAAATAAAAAQAAAAAAAAAKQ3JlYXRvckRhbwAAAAAAAgAAABMAAAAE",
+            "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAPdG9rZW5fd2FzbV9oYXNoAAAAA+4AAAAgAAAAAAAAAA1kYW9fd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+            "AAAAAAAAAAAAAAAPc2V0X3dhc21faGFzaGVzAAAAAAIAAAAAAAAAD3Rva2VuX3dhc21faGFzaAAAAAPuAAAAIAAAAAAAAAANZGFvX3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAA==",
+            "AAAAAAAAAAAAAAAPZ2V0X3dhc21faGFzaGVzAAAAAAAAAAABAAAD7QAAAAIAAAPuAAAAIAAAA+4AAAAg",
+            "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+            "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+            "AAAAAAAAAAAAAAANcHJvcG9zZV9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
+            "AAAAAAAAAAAAAAAMYWNjZXB0X2FkbWluAAAAAAAAAAA=",
+            "AAAAAAAAAAAAAAAFcGF1c2UAAAAAAAAAAAAAAA==",
+            "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAAAAAAAAA==",
+            "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+            "AAAAAAAAAAAAAAAQc2V0X2NyZWF0aW9uX2ZlZQAAAAMAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAA==",
+            "AAAAAAAAAAAAAAATcmVtb3ZlX2NyZWF0aW9uX2ZlZQAAAAAAAAAAAA==",
+            "AAAAAAAAAAAAAAAQZ2V0X2NyZWF0aW9uX2ZlZQAAAAAAAAABAAAD6AAAB9AAAAALQ3JlYXRpb25GZWUA",
+            "AAAAAAAAAAAAAAAKc2V0X3ByZXNldAAAAAAAAgAAAAAAAAAGcHJlc2V0AAAAAAAQAAAAAAAAAAZjb25maWcAAAAAB9AAAAAJRGFvQ29uZmlnAAAAAAAAAA==",
+            "AAAAAAAAAAAAAAANcmVtb3ZlX3ByZXNldAAAAAAAAAEAAAAAAAAABnByZXNldAAAAAAAEAAAAAA=",
+            "AAAAAAAAAAAAAAAKZ2V0X3ByZXNldAAAAAAAAQAAAAAAAAAGcHJlc2V0AAAAAAAQAAAAAQAAA+gAAAfQAAAACURhb0NvbmZpZwAAAA==",
+            "AAAAAAAAAAAAAAAQZ2V0X3ByZXNldF9uYW1lcwAAAAAAAAABAAAD6gAAABA=",
+            "AAAAAAAAAAAAAAAWY3JlYXRlX2Rhb19mcm9tX3ByZXNldAAAAAAABQAAAAAAAAAEaW5mbwAAB9AAAAAHQ29udGVudAAAAAAAAAAADGZ1bmRpbmdfZ29hbAAAAAsAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAGcHJlc2V0AAAAAAAQAAAAAAAAAAptaWxlc3RvbmVzAAAAAAPqAAAH0AAAAAlNaWxlc3RvbmUAAAAAAAABAAAD7QAAAAIAAAAGAAAAEw==",
+            "AAAAAAAAAAAAAAAKY3JlYXRlX2RhbwAAAAAABQAAAAAAAAAEaW5mbwAAB9AAAAAHQ29udGVudAAAAAAAAAAADGZ1bmRpbmdfZ29hbAAAAAsAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAACURhb0NvbmZpZwAAAAAAAAAAAAAKbWlsZXN0b25lcwAAAAAD6gAAB9AAAAAJTWlsZXN0b25lAAAAAAAAAQAAA+0AAAACAAAABgAAABM=",
+            "AAAAAAAAAAAAAAASdXBkYXRlX2Rhb19zdW1tYXJ5AAAAAAADAAAAAAAAAANkYW8AAAAAEwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAACURhb1N0YXR1cwAAAAAAAAAAAAAMdG90YWxfcmFpc2VkAAAACwAAAAA=",
+            "AAAAAAAAAAAAAAAPZ2V0X2Rhb19zdW1tYXJ5AAAAAAEAAAAAAAAABmRhb19pZAAAAAAABgAAAAEAAAPoAAAH0AAAAApEYW9TdW1tYXJ5AAA=",
+            "AAAAAAAAAAAAAAARZ2V0X2Rhb19zdW1tYXJpZXMAAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABgAAAAEAAAPqAAAH0AAAAApEYW9TdW1tYXJ5AAA=",
             "AAAAAAAAAAAAAAAHZ2V0X2RhbwAAAAABAAAAAAAAAAZkYW9faWQAAAAAAAYAAAABAAAD6AAAABM=",
+            "AAAAAAAAAAAAAAAKZ2V0X2Rhb19pZAAAAAAAAQAAAAAAAAADZGFvAAAAABMAAAABAAAD6AAAAAY=",
+            "AAAAAAAAAAAAAAATcHJlZGljdF9kYW9fYWRkcmVzcwAAAAABAAAAAAAAAAZkYW9faWQAAAAAAAYAAAABAAAAEw==",
             "AAAAAAAAAAAAAAANZ2V0X2Rhb19jb3VudAAAAAAAAAAAAAABAAAABg==",
             "AAAAAAAAAAAAAAASZ2V0X2Rhb3NfcGFnaW5hdGVkAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABgAAAAEAAAPqAAAD7QAAAAIAAAAGAAAAEw==",
-            "AAAAAAAAAAAAAAATZ2V0X2Rhb3NfYnlfY3JlYXRvcgAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAD6gAAA+0AAAACAAAABgAAABM=",
-            "AAAAAAAAAAAAAAAMZ2V0X2FsbF9kYW9zAAAAAAAAAAEAAAPqAAAAEw==",
-            "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9kYW8AAAAAAAUAAAAAAAAABG5hbWUAAAARAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAARAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAAGAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEXRva2VuX2NvbnRyYWN0X2lkAAAAAAAAEwAAAAA=",
-            "AAAAAAAAAAAAAAAGaW52ZXN0AAAAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABmFtb3VudAAAAAAABgAAAAA=",
-            "AAAAAAAAAAAAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAEAAAAAAAAAB2RldGFpbHMAAAAAEQAAAAEAAAAG",
-            "AAAAAAAAAAAAAAAEdm90ZQAAAAMAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAHc3VwcG9ydAAAAAABAAAAAA==",
-            "AAAAAAAAAAAAAAAQZXhlY3V0ZV9wcm9wb3NhbAAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAAA",
-            "AAAAAAAAAAAAAAATcmVjb3JkX2Rpc3RyaWJ1dGlvbgAAAAACAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAYAAAAA",
-            "AAAAAAAAAAAAAAAYZ2V0X2Rpc3RyaWJ1dGlvbl9oaXN0b3J5AAAAAAAAAAEAAAPqAAAD7QAAAAIAAAAGAAAABg==",
-            "AAAAAAAAAAAAAAATZ2V0X3Byb3Bvc2Fsc19jb3VudAAAAAAAAAAAAQAAAAY=",
-            "AAAAAAAAAAAAAAAUZ2V0X3Byb3Bvc2FsX2RldGFpbHMAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAABE=",
-            "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3ZvdGVzAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAs=",
-            "AAAAAAAAAAAAAAAVZ2V0X3Byb3Bvc2FsX2V4ZWN1dGVkAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAB",
-            "AAAAAAAAAAAAAAAQZ2V0X3ZvdGluZ19wb3dlcgAAAAEAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAEAAAAL",
-            "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAABg==",
-            "AAAAAAAAAAAAAAAXaXNfZnVuZGluZ19nb2FsX3JlYWNoZWQAAAAAAAAAAAEAAAAB",
-            "AAAAAAAAAAAAAAANZ2V0X2ludmVzdG9ycwAAAAAAAAAAAAABAAAD6gAAABM=",
-            "AAAAAAAAAAAAAAASZ2V0X3Rva2VuX2NvbnRyYWN0AAAAAAAAAAAAAQAAABM=",
-            "AAAAAAAAAAAAAAAPZ2V0X2ludmVzdG1lbnRzAAAAAAAAAAABAAAD7AAAABMAAAAG",
-            "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABE=",
-            "AAAAAAAAAAAAAAAPZ2V0X2Rlc2NyaXB0aW9uAAAAAAAAAAABAAAAEQ==",
-            "AAAAAAAAAAAAAAAQZ2V0X2Z1bmRpbmdfZ29hbAAAAAAAAAABAAAABg==",
-            "AAAAAAAAAAAAAAALZ2V0X2NyZWF0b3IAAAAAAAAAAAEAAAAT",
-            "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9wdGMAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-            "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-            "AAAAAAAAAAAAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
-            "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-            "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
-            "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAN3aG8AAAAAEwAAAAEAAAAL",
-            "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL"]), options);
+            "AAAAAAAAAAAAAAAYZ2V0X2Rhb19jb3VudF9ieV9jcmVhdG9yAAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAAAQ=",
+            "AAAAAAAAAAAAAAATZ2V0X2Rhb3NfYnlfY3JlYXRvcgAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAPtAAAAAgAAAAYAAAAT",
+            "AAAAAAAAAAAAAAAJbGlzdF9kYW9zAAAAAAAAAgAAAAAAAAAGY3Vyc29yAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD7QAAAAIAAAPqAAAD7QAAAAQAAAAGAAAAEwAAABAAAAfQAAAACURhb1N0YXR1cwAAAAAAA+gAAAAG",
+            "AAAAAgAAAAAAAAAAAAAACURhb1N0YXR1cwAAAAAAAAMAAAAAAAAAAAAAAAtGdW5kcmFpc2luZwAAAAAAAAAAAAAAAAZGdW5kZWQAAAAAAAAAAAAAAAAACVJlZnVuZGluZwAAAA==",
+            "AAAAAQAAAAAAAAAAAAAACURhb0NvbmZpZwAAAAAAABIAAAAAAAAADmFjY2VwdGVkX2Fzc2V0AAAAAAATAAAAAAAAABZhcHByb3ZhbF90aHJlc2hvbGRfYnBzAAAAAAAEAAAAAAAAABJjb21wbGlhbmNlX2VuYWJsZWQAAAAAAAEAAAAAAAAADmZ1bmRpbmdfcGVyaW9kAAAAAAAGAAAAAAAAAAxncmFjZV9wZXJpb2QAAAAGAAAAAAAAAAhoYXJkX2NhcAAAAAsAAAAAAAAADWxvY2t1cF9wZXJpb2QAAAAAAAAGAAAAAAAAABRtYXhfYWN0aXZlX3Byb3Bvc2FscwAAAAQAAAAAAAAADm1heF9pbnZlc3RtZW50AAAAAAALAAAAAAAAAA5taW5faW52ZXN0bWVudAAAAAAACwAAAAAAAAAScHJvZml0X2Rpc3RyaWJ1dG9yAAAAAAATAAAAAAAAABJwcm9wb3NhbF90aHJlc2hvbGQAAAAAAAsAAAAAAAAACnF1b3J1bV9icHMAAAAAAAQAAAAAAAAADnRpbWVsb2NrX2RlbGF5AAAAAAAGAAAAAAAAAAp0b2tlbl9uYW1lAAAAAAAQAAAAAAAAAAx0b2tlbl9zeW1ib2wAAAAQAAAAAAAAAAx2b3RpbmdfZGVsYXkAAAAGAAAAAAAAAA12b3RpbmdfcGVyaW9kAAAAAAAABg==",
+            "AAAAAQAAAAAAAAAAAAAAB0NvbnRlbnQAAAAAAwAAAAAAAAAEYm9keQAAB9AAAAALQ29udGVudEJvZHkAAAAAAAAAAAxjb250ZW50X2hhc2gAAAPuAAAAIAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+            "AAAAAQAAAAAAAAAAAAAACU1pbGVzdG9uZQAAAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAIZHVlX2RhdGUAAAAG",
+            "AAAAAgAAAAAAAAAAAAAAC0NvbnRlbnRCb2R5AAAAAAMAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAGSW5saW5lAAAAAAABAAAADgAAAAEAAAAAAAAAA1VyaQAAAAABAAAAEA=="]), options);
         this.options = options;
     }
     fromJSON = {
+        set_wasm_hashes: (this.txFromJSON),
+        get_wasm_hashes: (this.txFromJSON),
+        get_admin: (this.txFromJSON),
+        get_pending_admin: (this.txFromJSON),
+        propose_admin: (this.txFromJSON),
+        accept_admin: (this.txFromJSON),
+        pause: (this.txFromJSON),
+        unpause: (this.txFromJSON),
+        is_paused: (this.txFromJSON),
+        set_creation_fee: (this.txFromJSON),
+        remove_creation_fee: (this.txFromJSON),
+        get_creation_fee: (this.txFromJSON),
+        set_preset: (this.txFromJSON),
+        remove_preset: (this.txFromJSON),
+        get_preset: (this.txFromJSON),
+        get_preset_names: (this.txFromJSON),
+        create_dao_from_preset: (this.txFromJSON),
         create_dao: (this.txFromJSON),
+        update_dao_summary: (this.txFromJSON),
+        get_dao_summary: (this.txFromJSON),
+        get_dao_summaries: (this.txFromJSON),
         get_dao: (this.txFromJSON),
+        get_dao_id: (this.txFromJSON),
+        predict_dao_address: (this.txFromJSON),
         get_dao_count: (this.txFromJSON),
         get_daos_paginated: (this.txFromJSON),
+        get_dao_count_by_creator: (this.txFromJSON),
         get_daos_by_creator: (this.txFromJSON),
-        get_all_daos: (this.txFromJSON),
-        initialize_dao: (this.txFromJSON),
-        invest: (this.txFromJSON),
-        create_proposal: (this.txFromJSON),
-        vote: (this.txFromJSON),
-        execute_proposal: (this.txFromJSON),
-        record_distribution: (this.txFromJSON),
-        get_distribution_history: (this.txFromJSON),
-        get_proposals_count: (this.txFromJSON),
-        get_proposal_details: (this.txFromJSON),
-        get_proposal_votes: (this.txFromJSON),
-        get_proposal_executed: (this.txFromJSON),
-        get_voting_power: (this.txFromJSON),
-        get_total_raised: (this.txFromJSON),
-        is_funding_goal_reached: (this.txFromJSON),
-        get_investors: (this.txFromJSON),
-        get_token_contract: (this.txFromJSON),
-        get_investments: (this.txFromJSON),
-        get_name: (this.txFromJSON),
-        get_description: (this.txFromJSON),
-        get_funding_goal: (this.txFromJSON),
-        get_creator: (this.txFromJSON),
-        initialize_ptc: (this.txFromJSON),
-        get_admin: (this.txFromJSON),
-        mint: (this.txFromJSON),
-        burn: (this.txFromJSON),
-        transfer: (this.txFromJSON),
-        approve: (this.txFromJSON),
-        allowance: (this.txFromJSON),
-        transfer_from: (this.txFromJSON),
-        balance: (this.txFromJSON),
-        total_supply: (this.txFromJSON)
+        list_daos: (this.txFromJSON)
     };
 }
@@ -38,75 +38,71 @@ export const networks = {
   }
 } as const
 
-export const Errors = {
 
+export interface DaoSummary {
+  address: string;
+  created_at: u64;
+  creator: string;
+  funding_goal: i128;
+  id: u64;
+  name: string;
+  status: DaoStatus;
+  total_raised: i128;
 }
 
-export interface Client {
-  /**
-   * Construct and simulate a create_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  create_dao: ({name, description, funding_goal, creator}: {name: string, description: string, funding_goal: u64, creator: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<readonly [u64, string]>>
+export interface CreationFee {
+  amount: i128;
+  asset: string;
+  recipient: string;
+}
 
-  /**
-   * Construct and simulate a get_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  get_dao: ({dao_id}: {dao_id: u64}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+export type DataKey = {tag: "Dao", values: readonly [u64]} | {tag: "DaoId", values: readonly [string]} | {tag: "Summary", values: readonly [u64]} | {tag: "CreatorDaoCount", values: readonly [string]} | {tag: "CreatorDao", values: readonly [string, u32]};
+
+export type DaoStatus = {tag: "Fundraising", values: void} | {tag: "Funded", values: void} | {tag: "Refunding", values: void};
+
+export interface DaoConfig {
+  accepted_asset: string;
+  approval_threshold_bps: u32;
+  compliance_enabled: boolean;
+  funding_period: u64;
+  grace_period: u64;
+  hard_cap: i128;
+  lockup_period: u64;
+  max_active_proposals: u32;
+  max_investment: i128;
+  min_investment: i128;
+  profit_distributor: string;
+  proposal_threshold: i128;
+  quorum_bps: u32;
+  timelock_delay: u64;
+  token_name: string;
+  token_symbol: string;
+  voting_delay: u64;
+  voting_period: u64;
+}
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
+export interface Content {
+  body: ContentBody;
+  content_hash: Buffer;
+  title: string;
+}
 
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Option<string>>>
+export interface Milestone {
+  amount: i128;
+  description: string;
+  due_date: u64;
+}
 
-  /**
-   * Construct and simulate a get_dao_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  get_dao_count: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
+export type ContentBody = {tag: "None", values: void} | {tag: "Inline", values: readonly [Buffer]} | {tag: "Uri", values: readonly [string]};
 
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+export const Errors = {
+}
 
+export interface Client {
   /**
-   * Construct and simulate a get_daos_paginated transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_wasm_hashes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_daos_paginated: ({start, limit}: {start: u64, limit: u64}, options?: {
+  set_wasm_hashes: ({token_wasm_hash, dao_wasm_hash}: {token_wasm_hash: Buffer, dao_wasm_hash: Buffer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -121,12 +117,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_daos_by_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_wasm_hashes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_daos_by_creator: ({creator}: {creator: string}, options?: {
+  get_wasm_hashes: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -141,12 +137,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>
+  }) => Promise<AssembledTransaction<readonly [Buffer, Buffer]>>
 
   /**
-   * Construct and simulate a get_all_daos transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_all_daos: (options?: {
+  get_admin: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -161,12 +157,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<string>>>
+  }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a initialize_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize_dao: ({name, description, funding_goal, creator, token_contract_id}: {name: string, description: string, funding_goal: u64, creator: string, token_contract_id: string}, options?: {
+  get_pending_admin: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -181,12 +177,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Option<string>>>
 
   /**
-   * Construct and simulate a invest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  invest: ({investor, amount}: {investor: string, amount: u64}, options?: {
+  propose_admin: ({new_admin}: {new_admin: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -204,29 +200,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a create_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  create_proposal: ({details}: {details: string}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
-
-  /**
-   * Construct and simulate a vote transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  vote: ({voter, proposal_id, support}: {voter: string, proposal_id: u64, support: boolean}, options?: {
+  accept_admin: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -244,9 +220,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a execute_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  execute_proposal: ({proposal_id}: {proposal_id: u64}, options?: {
+  pause: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -264,9 +240,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a record_distribution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  record_distribution: ({timestamp, amount}: {timestamp: u64, amount: u64}, options?: {
+  unpause: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -284,29 +260,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_distribution_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  get_distribution_history: (options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<readonly [u64, u64]>>>
-
-  /**
-   * Construct and simulate a get_proposals_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_proposals_count: (options?: {
+  is_paused: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -321,12 +277,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a get_proposal_details transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_creation_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_proposal_details: ({proposal_id}: {proposal_id: u64}, options?: {
+  set_creation_fee: ({asset, amount, recipient}: {asset: string, amount: i128, recipient: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -341,12 +297,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_proposal_votes transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a remove_creation_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_proposal_votes: ({proposal_id}: {proposal_id: u64}, options?: {
+  remove_creation_fee: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -361,12 +317,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_proposal_executed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_creation_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_proposal_executed: ({proposal_id}: {proposal_id: u64}, options?: {
+  get_creation_fee: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -381,12 +337,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Option<CreationFee>>>
 
   /**
-   * Construct and simulate a get_voting_power transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a set_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_voting_power: ({voter}: {voter: string}, options?: {
+  set_preset: ({preset, config}: {preset: string, config: DaoConfig}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -401,12 +357,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_total_raised transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a remove_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_total_raised: (options?: {
+  remove_preset: ({preset}: {preset: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -421,12 +377,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a is_funding_goal_reached transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  is_funding_goal_reached: (options?: {
+  get_preset: ({preset}: {preset: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -441,12 +397,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<boolean>>
+  }) => Promise<AssembledTransaction<Option<DaoConfig>>>
 
   /**
-   * Construct and simulate a get_investors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_preset_names transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_investors: (options?: {
+  get_preset_names: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -464,9 +420,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<Array<string>>>
 
   /**
-   * Construct and simulate a get_token_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a create_dao_from_preset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_token_contract: (options?: {
+  create_dao_from_preset: ({info, funding_goal, creator, preset, milestones}: {info: Content, funding_goal: i128, creator: string, preset: string, milestones: Array<Milestone>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -481,12 +437,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<readonly [u64, string]>>
 
   /**
-   * Construct and simulate a get_investments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a create_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_investments: (options?: {
+  create_dao: ({info, funding_goal, creator, config, milestones}: {info: Content, funding_goal: i128, creator: string, config: DaoConfig, milestones: Array<Milestone>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -501,12 +457,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Map<string, u64>>>
+  }) => Promise<AssembledTransaction<readonly [u64, string]>>
 
   /**
-   * Construct and simulate a get_name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a update_dao_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_name: (options?: {
+  update_dao_summary: ({dao, status, total_raised}: {dao: string, status: DaoStatus, total_raised: i128}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -521,12 +477,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_description transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_dao_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_description: (options?: {
+  get_dao_summary: ({dao_id}: {dao_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -541,12 +497,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Option<DaoSummary>>>
 
   /**
-   * Construct and simulate a get_funding_goal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_dao_summaries transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_funding_goal: (options?: {
+  get_dao_summaries: ({start, limit}: {start: u64, limit: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -561,12 +517,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Array<DaoSummary>>>
 
   /**
-   * Construct and simulate a get_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_dao transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_creator: (options?: {
+  get_dao: ({dao_id}: {dao_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -581,12 +537,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<string>>
+  }) => Promise<AssembledTransaction<Option<string>>>
 
   /**
-   * Construct and simulate a initialize_ptc transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_dao_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize_ptc: ({admin}: {admin: string}, options?: {
+  get_dao_id: ({dao}: {dao: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -601,12 +557,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<Option<u64>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a predict_dao_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_admin: (options?: {
+  predict_dao_address: ({dao_id}: {dao_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -624,69 +580,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<string>>
 
   /**
-   * Construct and simulate a mint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  mint: ({to, amount}: {to: string, amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
-
-  /**
-   * Construct and simulate a burn transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  burn: ({from, amount}: {from: string, amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
-
-  /**
-   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  transfer: ({from, to, amount}: {from: string, to: string, amount: i128}, options?: {
-    /**
-     * The fee to pay for the transaction. Default: BASE_FEE
-     */
-    fee?: number;
-
-    /**
-     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-     */
-    timeoutInSeconds?: number;
-
-    /**
-     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-     */
-    simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
-
-  /**
-   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_dao_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  approve: ({owner, spender, amount}: {owner: string, spender: string, amount: i128}, options?: {
+  get_dao_count: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -701,12 +597,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_daos_paginated transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  allowance: ({owner, spender}: {owner: string, spender: string}, options?: {
+  get_daos_paginated: ({start, limit}: {start: u64, limit: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -721,12 +617,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>
 
   /**
-   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_dao_count_by_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  transfer_from: ({spender, from, to, amount}: {spender: string, from: string, to: string, amount: i128}, options?: {
+  get_dao_count_by_creator: ({creator}: {creator: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -741,12 +637,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_daos_by_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  balance: ({who}: {who: string}, options?: {
+  get_daos_by_creator: ({creator, start, limit}: {creator: string, start: u32, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -761,12 +657,12 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<Array<readonly [u64, string]>>>
 
   /**
-   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a list_daos transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  total_supply: (options?: {
+  list_daos: ({cursor, limit}: {cursor: u64, limit: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -781,11 +677,13 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<readonly [Array<readonly [u64, string, string, DaoStatus]>, Option<u64>]>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {admin, token_wasm_hash, dao_wasm_hash}: {admin: string, token_wasm_hash: Buffer, dao_wasm_hash: Buffer},
     /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -797,87 +695,80 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options)
+    return ContractClient.deploy({admin, token_wasm_hash, dao_wasm_hash}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAAAAAAAAAAAKY3JlYXRlX2RhbwAAAAAABAAAAAAAAAAEbmFtZQAAABEAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABEAAAAAAAAADGZ1bmRpbmdfZ29hbAAAAAYAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPtAAAAAgAAAAYAAAAT",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAACkRhb1N1bW1hcnkAAAAAAAgAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAALAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN0YXR1cwAAAAAH0AAAAAlEYW9TdGF0dXMAAAAAAAAAAAAADHRvdGFsX3JhaXNlZAAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAAC0NyZWF0aW9uRmVlAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAlyZWNpcGllbnQAAAAAAAAT",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAEAAAAAAAAAA0RhbwAAAAABAAAABgAAAAEAAAAAAAAABURhb0lkAAAAAAAAAQAAABMAAAABAAAAAAAAAAdTdW1tYXJ5AAAAAAEAAAAGAAAAAQAAAAAAAAAPQ3JlYXRvckRhb0NvdW50AAAAAAEAAAATAAAAAQAAAAAAAAAKQ3JlYXRvckRhbwAAAAAAAgAAABMAAAAE",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAPdG9rZW5fd2FzbV9oYXNoAAAAA+4AAAAgAAAAAAAAAA1kYW9fd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAAAAAAAPc2V0X3dhc21faGFzaGVzAAAAAAIAAAAAAAAAD3Rva2VuX3dhc21faGFzaAAAAAPuAAAAIAAAAAAAAAANZGFvX3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAAAAAAAPZ2V0X3dhc21faGFzaGVzAAAAAAAAAAABAAAD7QAAAAIAAAPuAAAAIAAAA+4AAAAg",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAANcHJvcG9zZV9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAMYWNjZXB0X2FkbWluAAAAAAAAAAA=",
+        "AAAAAAAAAAAAAAAFcGF1c2UAAAAAAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAHdW5wYXVzZQAAAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAQc2V0X2NyZWF0aW9uX2ZlZQAAAAMAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAlyZWNpcGllbnQAAAAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAATcmVtb3ZlX2NyZWF0aW9uX2ZlZQAAAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAQZ2V0X2NyZWF0aW9uX2ZlZQAAAAAAAAABAAAD6AAAB9AAAAALQ3JlYXRpb25GZWUA",
+        "AAAAAAAAAAAAAAAKc2V0X3ByZXNldAAAAAAAAgAAAAAAAAAGcHJlc2V0AAAAAAAQAAAAAAAAAAZjb25maWcAAAAAB9AAAAAJRGFvQ29uZmlnAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAANcmVtb3ZlX3ByZXNldAAAAAAAAAEAAAAAAAAABnByZXNldAAAAAAAEAAAAAA=",
+        "AAAAAAAAAAAAAAAKZ2V0X3ByZXNldAAAAAAAAQAAAAAAAAAGcHJlc2V0AAAAAAAQAAAAAQAAA+gAAAfQAAAACURhb0NvbmZpZwAAAA==",
+        "AAAAAAAAAAAAAAAQZ2V0X3ByZXNldF9uYW1lcwAAAAAAAAABAAAD6gAAABA=",
+        "AAAAAAAAAAAAAAAWY3JlYXRlX2Rhb19mcm9tX3ByZXNldAAAAAAABQAAAAAAAAAEaW5mbwAAB9AAAAAHQ29udGVudAAAAAAAAAAADGZ1bmRpbmdfZ29hbAAAAAsAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAGcHJlc2V0AAAAAAAQAAAAAAAAAAptaWxlc3RvbmVzAAAAAAPqAAAH0AAAAAlNaWxlc3RvbmUAAAAAAAABAAAD7QAAAAIAAAAGAAAAEw==",
+        "AAAAAAAAAAAAAAAKY3JlYXRlX2RhbwAAAAAABQAAAAAAAAAEaW5mbwAAB9AAAAAHQ29udGVudAAAAAAAAAAADGZ1bmRpbmdfZ29hbAAAAAsAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAACURhb0NvbmZpZwAAAAAAAAAAAAAKbWlsZXN0b25lcwAAAAAD6gAAB9AAAAAJTWlsZXN0b25lAAAAAAAAAQAAA+0AAAACAAAABgAAABM=",
+        "AAAAAAAAAAAAAAASdXBkYXRlX2Rhb19zdW1tYXJ5AAAAAAADAAAAAAAAAANkYW8AAAAAEwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAACURhb1N0YXR1cwAAAAAAAAAAAAAMdG90YWxfcmFpc2VkAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAPZ2V0X2Rhb19zdW1tYXJ5AAAAAAEAAAAAAAAABmRhb19pZAAAAAAABgAAAAEAAAPoAAAH0AAAAApEYW9TdW1tYXJ5AAA=",
+        "AAAAAAAAAAAAAAARZ2V0X2Rhb19zdW1tYXJpZXMAAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABgAAAAEAAAPqAAAH0AAAAApEYW9TdW1tYXJ5AAA=",
         "AAAAAAAAAAAAAAAHZ2V0X2RhbwAAAAABAAAAAAAAAAZkYW9faWQAAAAAAAYAAAABAAAD6AAAABM=",
+        "AAAAAAAAAAAAAAAKZ2V0X2Rhb19pZAAAAAAAAQAAAAAAAAADZGFvAAAAABMAAAABAAAD6AAAAAY=",
+        "AAAAAAAAAAAAAAATcHJlZGljdF9kYW9fYWRkcmVzcwAAAAABAAAAAAAAAAZkYW9faWQAAAAAAAYAAAABAAAAEw==",
         "AAAAAAAAAAAAAAANZ2V0X2Rhb19jb3VudAAAAAAAAAAAAAABAAAABg==",
         "AAAAAAAAAAAAAAASZ2V0X2Rhb3NfcGFnaW5hdGVkAAAAAAACAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABgAAAAEAAAPqAAAD7QAAAAIAAAAGAAAAEw==",
-        "AAAAAAAAAAAAAAATZ2V0X2Rhb3NfYnlfY3JlYXRvcgAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAD6gAAA+0AAAACAAAABgAAABM=",
-        "AAAAAAAAAAAAAAAMZ2V0X2FsbF9kYW9zAAAAAAAAAAEAAAPqAAAAEw==",
-        "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9kYW8AAAAAAAUAAAAAAAAABG5hbWUAAAARAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAARAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAAGAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEXRva2VuX2NvbnRyYWN0X2lkAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAGaW52ZXN0AAAAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABmFtb3VudAAAAAAABgAAAAA=",
-        "AAAAAAAAAAAAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAEAAAAAAAAAB2RldGFpbHMAAAAAEQAAAAEAAAAG",
-        "AAAAAAAAAAAAAAAEdm90ZQAAAAMAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAHc3VwcG9ydAAAAAABAAAAAA==",
-        "AAAAAAAAAAAAAAAQZXhlY3V0ZV9wcm9wb3NhbAAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAAA",
-        "AAAAAAAAAAAAAAATcmVjb3JkX2Rpc3RyaWJ1dGlvbgAAAAACAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAYAAAAA",
-        "AAAAAAAAAAAAAAAYZ2V0X2Rpc3RyaWJ1dGlvbl9oaXN0b3J5AAAAAAAAAAEAAAPqAAAD7QAAAAIAAAAGAAAABg==",
-        "AAAAAAAAAAAAAAATZ2V0X3Byb3Bvc2Fsc19jb3VudAAAAAAAAAAAAQAAAAY=",
-        "AAAAAAAAAAAAAAAUZ2V0X3Byb3Bvc2FsX2RldGFpbHMAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAABE=",
-        "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3ZvdGVzAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAs=",
-        "AAAAAAAAAAAAAAAVZ2V0X3Byb3Bvc2FsX2V4ZWN1dGVkAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAB",
-        "AAAAAAAAAAAAAAAQZ2V0X3ZvdGluZ19wb3dlcgAAAAEAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAEAAAAL",
-        "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAABg==",
-        "AAAAAAAAAAAAAAAXaXNfZnVuZGluZ19nb2FsX3JlYWNoZWQAAAAAAAAAAAEAAAAB",
-        "AAAAAAAAAAAAAAANZ2V0X2ludmVzdG9ycwAAAAAAAAAAAAABAAAD6gAAABM=",
-        "AAAAAAAAAAAAAAASZ2V0X3Rva2VuX2NvbnRyYWN0AAAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAPZ2V0X2ludmVzdG1lbnRzAAAAAAAAAAABAAAD7AAAABMAAAAG",
-        "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABE=",
-        "AAAAAAAAAAAAAAAPZ2V0X2Rlc2NyaXB0aW9uAAAAAAAAAAABAAAAEQ==",
-        "AAAAAAAAAAAAAAAQZ2V0X2Z1bmRpbmdfZ29hbAAAAAAAAAABAAAABg==",
-        "AAAAAAAAAAAAAAALZ2V0X2NyZWF0b3IAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9wdGMAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-        "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
-        "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-        "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
-        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-        "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAN3aG8AAAAAEwAAAAEAAAAL",
-        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL" ]),
+        "AAAAAAAAAAAAAAAYZ2V0X2Rhb19jb3VudF9ieV9jcmVhdG9yAAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAATZ2V0X2Rhb3NfYnlfY3JlYXRvcgAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAPtAAAAAgAAAAYAAAAT",
+        "AAAAAAAAAAAAAAAJbGlzdF9kYW9zAAAAAAAAAgAAAAAAAAAGY3Vyc29yAAAAAAAGAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD7QAAAAIAAAPqAAAD7QAAAAQAAAAGAAAAEwAAABAAAAfQAAAACURhb1N0YXR1cwAAAAAAA+gAAAAG",
+        "AAAAAgAAAAAAAAAAAAAACURhb1N0YXR1cwAAAAAAAAMAAAAAAAAAAAAAAAtGdW5kcmFpc2luZwAAAAAAAAAAAAAAAAZGdW5kZWQAAAAAAAAAAAAAAAAACVJlZnVuZGluZwAAAA==",
+        "AAAAAQAAAAAAAAAAAAAACURhb0NvbmZpZwAAAAAAABIAAAAAAAAADmFjY2VwdGVkX2Fzc2V0AAAAAAATAAAAAAAAABZhcHByb3ZhbF90aHJlc2hvbGRfYnBzAAAAAAAEAAAAAAAAABJjb21wbGlhbmNlX2VuYWJsZWQAAAAAAAEAAAAAAAAADmZ1bmRpbmdfcGVyaW9kAAAAAAAGAAAAAAAAAAxncmFjZV9wZXJpb2QAAAAGAAAAAAAAAAhoYXJkX2NhcAAAAAsAAAAAAAAADWxvY2t1cF9wZXJpb2QAAAAAAAAGAAAAAAAAABRtYXhfYWN0aXZlX3Byb3Bvc2FscwAAAAQAAAAAAAAADm1heF9pbnZlc3RtZW50AAAAAAALAAAAAAAAAA5taW5faW52ZXN0bWVudAAAAAAACwAAAAAAAAAScHJvZml0X2Rpc3RyaWJ1dG9yAAAAAAATAAAAAAAAABJwcm9wb3NhbF90aHJlc2hvbGQAAAAAAAsAAAAAAAAACnF1b3J1bV9icHMAAAAAAAQAAAAAAAAADnRpbWVsb2NrX2RlbGF5AAAAAAAGAAAAAAAAAAp0b2tlbl9uYW1lAAAAAAAQAAAAAAAAAAx0b2tlbl9zeW1ib2wAAAAQAAAAAAAAAAx2b3RpbmdfZGVsYXkAAAAGAAAAAAAAAA12b3RpbmdfcGVyaW9kAAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAAB0NvbnRlbnQAAAAAAwAAAAAAAAAEYm9keQAAB9AAAAALQ29udGVudEJvZHkAAAAAAAAAAAxjb250ZW50X2hhc2gAAAPuAAAAIAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
+        "AAAAAQAAAAAAAAAAAAAACU1pbGVzdG9uZQAAAAAAAAMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAIZHVlX2RhdGUAAAAG",
+        "AAAAAgAAAAAAAAAAAAAAC0NvbnRlbnRCb2R5AAAAAAMAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAGSW5saW5lAAAAAAABAAAADgAAAAEAAAAAAAAAA1VyaQAAAAABAAAAEA==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    create_dao: this.txFromJSON<readonly [u64, string]>,
+    set_wasm_hashes: this.txFromJSON<null>,
+        get_wasm_hashes: this.txFromJSON<readonly [Buffer, Buffer]>,
+        get_admin: this.txFromJSON<string>,
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<null>,
+        pause: this.txFromJSON<null>,
+        unpause: this.txFromJSON<null>,
+        is_paused: this.txFromJSON<boolean>,
+        set_creation_fee: this.txFromJSON<null>,
+        remove_creation_fee: this.txFromJSON<null>,
+        get_creation_fee: this.txFromJSON<Option<CreationFee>>,
+        set_preset: this.txFromJSON<null>,
+        remove_preset: this.txFromJSON<null>,
+        get_preset: this.txFromJSON<Option<DaoConfig>>,
+        get_preset_names: this.txFromJSON<Array<string>>,
+        create_dao_from_preset: this.txFromJSON<readonly [u64, string]>,
+        create_dao: this.txFromJSON<readonly [u64, string]>,
+        update_dao_summary: this.txFromJSON<null>,
+        get_dao_summary: this.txFromJSON<Option<DaoSummary>>,
+        get_dao_summaries: this.txFromJSON<Array<DaoSummary>>,
         get_dao: this.txFromJSON<Option<string>>,
+        get_dao_id: this.txFromJSON<Option<u64>>,
+        predict_dao_address: this.txFromJSON<string>,
         get_dao_count: this.txFromJSON<u64>,
         get_daos_paginated: this.txFromJSON<Array<readonly [u64, string]>>,
+        get_dao_count_by_creator: this.txFromJSON<u32>,
         get_daos_by_creator: this.txFromJSON<Array<readonly [u64, string]>>,
-        get_all_daos: this.txFromJSON<Array<string>>,
-        initialize_dao: this.txFromJSON<null>,
-        invest: this.txFromJSON<null>,
-        create_proposal: this.txFromJSON<u64>,
-        vote: this.txFromJSON<null>,
-        execute_proposal: this.txFromJSON<null>,
-        record_distribution: this.txFromJSON<null>,
-        get_distribution_history: this.txFromJSON<Array<readonly [u64, u64]>>,
-        get_proposals_count: this.txFromJSON<u64>,
-        get_proposal_details: this.txFromJSON<string>,
-        get_proposal_votes: this.txFromJSON<i128>,
-        get_proposal_executed: this.txFromJSON<boolean>,
-        get_voting_power: this.txFromJSON<i128>,
-        get_total_raised: this.txFromJSON<u64>,
-        is_funding_goal_reached: this.txFromJSON<boolean>,
-        get_investors: this.txFromJSON<Array<string>>,
-        get_token_contract: this.txFromJSON<string>,
-        get_investments: this.txFromJSON<Map<string, u64>>,
-        get_name: this.txFromJSON<string>,
-        get_description: this.txFromJSON<string>,
-        get_funding_goal: this.txFromJSON<u64>,
-        get_creator: this.txFromJSON<string>,
-        initialize_ptc: this.txFromJSON<null>,
-        get_admin: this.txFromJSON<string>,
-        mint: this.txFromJSON<null>,
-        burn: this.txFromJSON<null>,
-        transfer: this.txFromJSON<null>,
-        approve: this.txFromJSON<null>,
-        allowance: this.txFromJSON<i128>,
-        transfer_from: this.txFromJSON<null>,
-        balance: this.txFromJSON<i128>,
-        total_supply: this.txFromJSON<i128>
+        list_daos: this.txFromJSON<readonly [Array<readonly [u64, string, string, DaoStatus]>, Option<u64>]>
   }
 }
\ No newline at end of file
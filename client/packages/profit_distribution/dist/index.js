@@ -13,81 +13,53 @@ export const networks = {
         contractId: "CARPDCBBDPMVGAB3NAGZMWKZ7ZCVCYIJHGAJF6YJA436ZUCTCIVHPHGJ",
     }
 };
-export const Errors = {};
+export const Errors = {
+    1: { message: "Overflow" },
+    2: { message: "DivisionByZero" }
+};
 export class Client extends ContractClient {
     options;
     static async deploy(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { factory }, 
     /** Options for initalizing a Client as well as for calling a method, with extras specific to deploying. */
     options) {
-        return ContractClient.deploy(null, options);
+        return ContractClient.deploy({ factory }, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAAAAAAAAAAAKZGlzdHJpYnV0ZQAAAAAAAgAAAAAAAAAIZGFvX2FkZHIAAAATAAAAAAAAAA1wcm9maXRfYW1vdW50AAAAAAAABgAAAAA=",
-            "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9kYW8AAAAAAAUAAAAAAAAABG5hbWUAAAARAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAARAAAAAAAAAAxmdW5kaW5nX2dvYWwAAAAGAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEXRva2VuX2NvbnRyYWN0X2lkAAAAAAAAEwAAAAA=",
-            "AAAAAAAAAAAAAAAGaW52ZXN0AAAAAAACAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABmFtb3VudAAAAAAABgAAAAA=",
-            "AAAAAAAAAAAAAAAPY3JlYXRlX3Byb3Bvc2FsAAAAAAEAAAAAAAAAB2RldGFpbHMAAAAAEQAAAAEAAAAG",
-            "AAAAAAAAAAAAAAAEdm90ZQAAAAMAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAAAAAAHc3VwcG9ydAAAAAABAAAAAA==",
-            "AAAAAAAAAAAAAAAQZXhlY3V0ZV9wcm9wb3NhbAAAAAEAAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAYAAAAA",
-            "AAAAAAAAAAAAAAATcmVjb3JkX2Rpc3RyaWJ1dGlvbgAAAAACAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAYAAAAA",
-            "AAAAAAAAAAAAAAAYZ2V0X2Rpc3RyaWJ1dGlvbl9oaXN0b3J5AAAAAAAAAAEAAAPqAAAD7QAAAAIAAAAGAAAABg==",
-            "AAAAAAAAAAAAAAATZ2V0X3Byb3Bvc2Fsc19jb3VudAAAAAAAAAAAAQAAAAY=",
-            "AAAAAAAAAAAAAAAUZ2V0X3Byb3Bvc2FsX2RldGFpbHMAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAABE=",
-            "AAAAAAAAAAAAAAASZ2V0X3Byb3Bvc2FsX3ZvdGVzAAAAAAABAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAGAAAAAQAAAAs=",
-            "AAAAAAAAAAAAAAAVZ2V0X3Byb3Bvc2FsX2V4ZWN1dGVkAAAAAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABgAAAAEAAAAB",
-            "AAAAAAAAAAAAAAAQZ2V0X3ZvdGluZ19wb3dlcgAAAAEAAAAAAAAABXZvdGVyAAAAAAAAEwAAAAEAAAAL",
-            "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAABg==",
-            "AAAAAAAAAAAAAAAXaXNfZnVuZGluZ19nb2FsX3JlYWNoZWQAAAAAAAAAAAEAAAAB",
-            "AAAAAAAAAAAAAAANZ2V0X2ludmVzdG9ycwAAAAAAAAAAAAABAAAD6gAAABM=",
-            "AAAAAAAAAAAAAAASZ2V0X3Rva2VuX2NvbnRyYWN0AAAAAAAAAAAAAQAAABM=",
-            "AAAAAAAAAAAAAAAPZ2V0X2ludmVzdG1lbnRzAAAAAAAAAAABAAAD7AAAABMAAAAG",
-            "AAAAAAAAAAAAAAAIZ2V0X25hbWUAAAAAAAAAAQAAABE=",
-            "AAAAAAAAAAAAAAAPZ2V0X2Rlc2NyaXB0aW9uAAAAAAAAAAABAAAAEQ==",
-            "AAAAAAAAAAAAAAAQZ2V0X2Z1bmRpbmdfZ29hbAAAAAAAAAABAAAABg==",
-            "AAAAAAAAAAAAAAALZ2V0X2NyZWF0b3IAAAAAAAAAAAEAAAAT",
-            "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9wdGMAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
-            "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-            "AAAAAAAAAAAAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
-            "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
-            "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
-            "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
-            "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAN3aG8AAAAAEwAAAAEAAAAL",
-            "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL"]), options);
+        super(new ContractSpec(["AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAAgAAAAAAAAAIT3ZlcmZsb3cAAAABAAAAAAAAAA5EaXZpc2lvbkJ5WmVybwAAAAAAAg==",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABQAAAAEAAAAAAAAABVJvdW5kAAAAAAAAAgAAABMAAAAGAAAAAQAAAAAAAAAKUm91bmRDb3VudAAAAAAAAQAAABMAAAABAAAAAAAAAAhTY2hlZHVsZQAAAAEAAAATAAAAAQAAAAAAAAAIUmVpbnZlc3QAAAACAAAAEwAAABMAAAABAAAAAAAAAAlDbGFpbWFibGUAAAAAAAADAAAAEwAAABMAAAAT",
+            "AAAAAQAAAAAAAAAAAAAAEURpc3RyaWJ1dGlvblJvdW5kAAAAAAAACAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAACGNvbXBsZXRlAAAAAQAAAAAAAAAGY3Vyc29yAAAAAAAEAAAAAAAAAAZmdW5kZXIAAAAAABMAAAAAAAAABHBhaWQAAAALAAAAAAAAAAhzbmFwc2hvdAAAAAYAAAAAAAAADHRvdGFsX3NoYXJlcwAAAAs=",
+            "AAAAAQAAAAAAAAAAAAAAFERpc3RyaWJ1dGlvblNjaGVkdWxlAAAABgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAVhc3NldAAAAAAAABMAAAAAAAAACGludGVydmFsAAAABgAAAAAAAAAOa2VlcGVyX2ZlZV9icHMAAAAAAAQAAAAAAAAACG5leHRfcnVuAAAABgAAAAAAAAAGc291cmNlAAAAAAAT",
+            "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAEAAAAAAAAAB2ZhY3RvcnkAAAAAEwAAAAA=",
+            "AAAAAAAAAAAAAAAKZGlzdHJpYnV0ZQAAAAAAAgAAAAAAAAAIZGFvX2FkZHIAAAATAAAAAAAAAA1wcm9maXRfYW1vdW50AAAAAAAACwAAAAA=",
+            "AAAAAAAAAAAAAAAKb3Blbl9yb3VuZAAAAAAABAAAAAAAAAADZGFvAAAAABMAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAABg==",
+            "AAAAAAAAAAAAAAAQZGlzdHJpYnV0ZV9iYXRjaAAAAAQAAAAAAAAAA2RhbwAAAAATAAAAAAAAAAhyb3VuZF9pZAAAAAYAAAAAAAAABmN1cnNvcgAAAAAABAAAAAAAAAAJbWF4X2NvdW50AAAAAAAABAAAAAEAAAfQAAAAEURpc3RyaWJ1dGlvblJvdW5kAAAA",
+            "AAAAAAAAAAAAAAAMc2V0X3NjaGVkdWxlAAAABgAAAAAAAAADZGFvAAAAABMAAAAAAAAABnNvdXJjZQAAAAAAEwAAAAAAAAAFYXNzZXQAAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACGludGVydmFsAAAABgAAAAAAAAAOa2VlcGVyX2ZlZV9icHMAAAAAAAQAAAAA",
+            "AAAAAAAAAAAAAAAPY2FuY2VsX3NjaGVkdWxlAAAAAAEAAAAAAAAAA2RhbwAAAAATAAAAAA==",
+            "AAAAAAAAAAAAAAAMZ2V0X3NjaGVkdWxlAAAAAQAAAAAAAAADZGFvAAAAABMAAAABAAAD6AAAB9AAAAAURGlzdHJpYnV0aW9uU2NoZWR1bGU=",
+            "AAAAAAAAAAAAAAAEcG9rZQAAAAIAAAAAAAAAA2RhbwAAAAATAAAAAAAAAAZrZWVwZXIAAAAAABMAAAABAAAABg==",
+            "AAAAAAAAAAAAAAAMc2V0X3JlaW52ZXN0AAAAAwAAAAAAAAADZGFvAAAAABMAAAAAAAAACGludmVzdG9yAAAAEwAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAA==",
+            "AAAAAAAAAAAAAAAMZ2V0X3JlaW52ZXN0AAAAAgAAAAAAAAADZGFvAAAAABMAAAAAAAAACGludmVzdG9yAAAAEwAAAAEAAAAB",
+            "AAAAAAAAAAAAAAANZ2V0X2NsYWltYWJsZQAAAAAAAAMAAAAAAAAAA2RhbwAAAAATAAAAAAAAAAhpbnZlc3RvcgAAABMAAAAAAAAABWFzc2V0AAAAAAAAEwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAFY2xhaW0AAAAAAAADAAAAAAAAAANkYW8AAAAAEwAAAAAAAAAIaW52ZXN0b3IAAAATAAAAAAAAAAVhc3NldAAAAAAAABMAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAJZ2V0X3JvdW5kAAAAAAAAAgAAAAAAAAADZGFvAAAAABMAAAAAAAAACHJvdW5kX2lkAAAABgAAAAEAAAfQAAAAEURpc3RyaWJ1dGlvblJvdW5kAAAA",
+            "AAAAAAAAAAAAAAAPZ2V0X3JvdW5kX2NvdW50AAAAAAEAAAAAAAAAA2RhbwAAAAATAAAAAQAAAAY="]), options);
         this.options = options;
     }
     fromJSON = {
         distribute: (this.txFromJSON),
-        initialize_dao: (this.txFromJSON),
-        invest: (this.txFromJSON),
-        create_proposal: (this.txFromJSON),
-        vote: (this.txFromJSON),
-        execute_proposal: (this.txFromJSON),
-        record_distribution: (this.txFromJSON),
-        get_distribution_history: (this.txFromJSON),
-        get_proposals_count: (this.txFromJSON),
-        get_proposal_details: (this.txFromJSON),
-        get_proposal_votes: (this.txFromJSON),
-        get_proposal_executed: (this.txFromJSON),
-        get_voting_power: (this.txFromJSON),
-        get_total_raised: (this.txFromJSON),
-        is_funding_goal_reached: (this.txFromJSON),
-        get_investors: (this.txFromJSON),
-        get_token_contract: (this.txFromJSON),
-        get_investments: (this.txFromJSON),
-        get_name: (this.txFromJSON),
-        get_description: (this.txFromJSON),
-        get_funding_goal: (this.txFromJSON),
-        get_creator: (this.txFromJSON),
-        initialize_ptc: (this.txFromJSON),
-        get_admin: (this.txFromJSON),
-        mint: (this.txFromJSON),
-        burn: (this.txFromJSON),
-        transfer: (this.txFromJSON),
-        approve: (this.txFromJSON),
-        allowance: (this.txFromJSON),
-        transfer_from: (this.txFromJSON),
-        balance: (this.txFromJSON),
-        total_supply: (this.txFromJSON)
+        open_round: (this.txFromJSON),
+        distribute_batch: (this.txFromJSON),
+        set_schedule: (this.txFromJSON),
+        cancel_schedule: (this.txFromJSON),
+        get_schedule: (this.txFromJSON),
+        poke: (this.txFromJSON),
+        set_reinvest: (this.txFromJSON),
+        get_reinvest: (this.txFromJSON),
+        get_claimable: (this.txFromJSON),
+        claim: (this.txFromJSON),
+        get_round: (this.txFromJSON),
+        get_round_count: (this.txFromJSON)
     };
 }
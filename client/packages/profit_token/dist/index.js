@@ -13,7 +13,9 @@ export const networks = {
         contractId: "CCFU4LOQZK2TWIQIN6FLDERPAAJ2U2J6Q6DMTPUADGZTBHBFT5XENNLF",
     }
 };
-export const Errors = {};
+export const Errors = {
+    1: { message: "Overflow" }
+};
 export class Client extends ContractClient {
     options;
     static async deploy(
@@ -22,14 +24,42 @@ export class Client extends ContractClient {
         return ContractClient.deploy(null, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9wdGMAAAAAAAEAAAAAAAAABWFkbWluAAAAAAAAEwAAAAA=",
+        super(new ContractSpec(["AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAAQAAAAAAAAAIT3ZlcmZsb3cAAAAB",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABwAAAAEAAAAAAAAAEkJhbGFuY2VDaGVja3BvaW50cwAAAAAAAQAAABMAAAABAAAAAAAAAAZIb2xkZXIAAAAAAAEAAAAEAAAAAQAAAAAAAAAPVm90ZUNoZWNrcG9pbnRzAAAAAAEAAAATAAAAAAAAAAAAAAARU3VwcGx5Q2hlY2twb2ludHMAAAAAAAABAAAAAAAAAAhEZWxlZ2F0ZQAAAAEAAAATAAAAAQAAAAAAAAAHTG9ja3VwcwAAAAABAAAAEwAAAAEAAAAAAAAABkZyb3plbgAAAAAAAQAAABM=",
+            "AAAAAAAAAAAAAAAOaW5pdGlhbGl6ZV9wdGMAAAAAAAQAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAABnN5bWJvbAAAAAAAEAAAAAAAAAASY29tcGxpYW5jZV9lbmFibGVkAAAAAAABAAAAAA==",
             "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+            "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+            "AAAAAAAAAAAAAAAOc2V0X21heF9zdXBwbHkAAAAAAAEAAAAAAAAACm1heF9zdXBwbHkAAAAAA+gAAAALAAAAAA==",
+            "AAAAAAAAAAAAAAAOZ2V0X21heF9zdXBwbHkAAAAAAAAAAAABAAAD6AAAAAs=",
+            "AAAAAAAAAAAAAAAEbmFtZQAAAAAAAAABAAAAEA==",
+            "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABA=",
+            "AAAAAAAAAAAAAAARc2V0X3RyYW5zZmVyX2dhdGUAAAAAAAABAAAAAAAAAARnYXRlAAAD6AAAABMAAAAA",
+            "AAAAAAAAAAAAAAARZ2V0X3RyYW5zZmVyX2dhdGUAAAAAAAAAAAAAAQAAA+gAAAAT",
             "AAAAAAAAAAAAAAAEbWludAAAAAIAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAAAAAAAKbWludF9iYXRjaAAAAAAAAQAAAAAAAAAKcmVjaXBpZW50cwAAAAAD6gAAA+0AAAACAAAAEwAAAAsAAAAA",
+            "AAAAAAAAAAAAAAALbWludF9sb2NrZWQAAAAAAwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALdW5sb2NrX3RpbWUAAAAABgAAAAA=",
+            "AAAAAAAAAAAAAAAVc2V0X3RyYW5zZmVyc19lbmFibGVkAAAAAAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAA==",
+            "AAAAAAAAAAAAAAARdHJhbnNmZXJzX2VuYWJsZWQAAAAAAAAAAAAAAQAAAAE=",
+            "AAAAAAAAAAAAAAAObG9ja2VkX2JhbGFuY2UAAAAAAAEAAAAAAAAAA3dobwAAAAATAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAALZ2V0X2xvY2t1cHMAAAAAAQAAAAAAAAADd2hvAAAAABMAAAABAAAD6gAAA+0AAAACAAAABgAAAAs=",
             "AAAAAAAAAAAAAAAEYnVybgAAAAIAAAAAAAAABGZyb20AAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAAAAAAASY29tcGxpYW5jZV9lbmFibGVkAAAAAAAAAAAAAQAAAAE=",
+            "AAAAAAAAAAAAAAAGZnJlZXplAAAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAAA",
+            "AAAAAAAAAAAAAAAIdW5mcmVlemUAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAAA",
+            "AAAAAAAAAAAAAAAJaXNfZnJvemVuAAAAAAAAAQAAAAAAAAAHYWNjb3VudAAAAAATAAAAAQAAAAE=",
+            "AAAAAAAAAAAAAAAIY2xhd2JhY2sAAAACAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
             "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
             "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAADAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
             "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
             "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+            "AAAAAAAAAAAAAAAIZGVsZWdhdGUAAAACAAAAAAAAAAlkZWxlZ2F0b3IAAAAAAAATAAAAAAAAAAlkZWxlZ2F0ZWUAAAAAAAATAAAAAA==",
+            "AAAAAAAAAAAAAAAJZGVsZWdhdGVzAAAAAAAAAQAAAAAAAAADd2hvAAAAABMAAAABAAAD6AAAABM=",
+            "AAAAAAAAAAAAAAAJZ2V0X3ZvdGVzAAAAAAAAAQAAAAAAAAADd2hvAAAAABMAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAOZ2V0X3Bhc3Rfdm90ZXMAAAAAAAIAAAAAAAAAA3dobwAAAAATAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAAVZ2V0X3Bhc3RfdG90YWxfc3VwcGx5AAAAAAAAAQAAAAAAAAAJdGltZXN0YW1wAAAAAAAABgAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAQZ2V0X3Bhc3RfYmFsYW5jZQAAAAIAAAAAAAAAA3dobwAAAAATAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAGAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAAMaG9sZGVyX2NvdW50AAAAAAAAAAEAAAAE",
+            "AAAAAAAAAAAAAAALZ2V0X2hvbGRlcnMAAAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAABM=",
             "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAN3aG8AAAAAEwAAAAEAAAAL",
             "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL"]), options);
         this.options = options;
@@ -37,12 +67,38 @@ export class Client extends ContractClient {
     fromJSON = {
         initialize_ptc: (this.txFromJSON),
         get_admin: (this.txFromJSON),
+        set_admin: (this.txFromJSON),
+        set_max_supply: (this.txFromJSON),
+        get_max_supply: (this.txFromJSON),
+        name: (this.txFromJSON),
+        symbol: (this.txFromJSON),
+        set_transfer_gate: (this.txFromJSON),
+        get_transfer_gate: (this.txFromJSON),
         mint: (this.txFromJSON),
+        mint_batch: (this.txFromJSON),
+        mint_locked: (this.txFromJSON),
+        set_transfers_enabled: (this.txFromJSON),
+        transfers_enabled: (this.txFromJSON),
+        locked_balance: (this.txFromJSON),
+        get_lockups: (this.txFromJSON),
         burn: (this.txFromJSON),
+        compliance_enabled: (this.txFromJSON),
+        freeze: (this.txFromJSON),
+        unfreeze: (this.txFromJSON),
+        is_frozen: (this.txFromJSON),
+        clawback: (this.txFromJSON),
         transfer: (this.txFromJSON),
         approve: (this.txFromJSON),
         allowance: (this.txFromJSON),
         transfer_from: (this.txFromJSON),
+        delegate: (this.txFromJSON),
+        delegates: (this.txFromJSON),
+        get_votes: (this.txFromJSON),
+        get_past_votes: (this.txFromJSON),
+        get_past_total_supply: (this.txFromJSON),
+        get_past_balance: (this.txFromJSON),
+        holder_count: (this.txFromJSON),
+        get_holders: (this.txFromJSON),
         balance: (this.txFromJSON),
         total_supply: (this.txFromJSON)
     };
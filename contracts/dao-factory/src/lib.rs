@@ -1,83 +1,415 @@
 #![no_std]
-use dao::DAOContractClient;
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Map, Symbol, Vec};
+use dao::{Content, DAOContractClient, DaoConfig, DaoStatus, Milestone};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, token::TokenClient, Address, Bytes, BytesN, Env, Map,
+    String, Vec,
+};
+
+// Cached listing data for one DAO, so frontends can render a list of DAOs
+// without calling into each of them
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DaoSummary {
+    pub id: u64,
+    pub address: Address,
+    pub name: String,
+    pub creator: Address,
+    pub funding_goal: i128,
+    pub total_raised: i128,
+    pub status: DaoStatus,
+    pub created_at: u64,
+}
+
+// Fee charged to the creator on `create_dao`, paid in a SEP-41 asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationFee {
+    pub asset: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+// Per-DAO records, each kept in its own persistent entry so that no factory
+// call has to load data for every DAO ever created
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // DAO ID -> DAO address
+    Dao(u64),
+    // DAO address -> DAO ID
+    DaoId(Address),
+    // DAO ID -> DaoSummary
+    Summary(u64),
+    // Number of DAOs created by an address
+    CreatorDaoCount(Address),
+    // (creator, n) -> ID of the creator's n-th DAO
+    CreatorDao(Address, u32),
+}
+
+// Upper bound on entries returned by any paginated getter, so a single call
+// always stays within the read budget
+pub const MAX_PAGE_SIZE: u32 = 25;
+
+// Salt tags so the token and DAO deployed for the same ID never collide
+const SALT_TAG_TOKEN: u8 = 0;
+const SALT_TAG_DAO: u8 = 1;
+
+// Derive a deterministic deployment salt from the DAO ID: [tag, 0.., id (big endian)]
+fn deploy_salt(env: &Env, dao_id: u64, tag: u8) -> BytesN<32> {
+    let mut salt = [0u8; 32];
+    salt[0] = tag;
+    salt[24..].copy_from_slice(&dao_id.to_be_bytes());
+    BytesN::from_array(env, &salt)
+}
+
+// Load the factory admin and require its authorization
+fn require_admin(env: &Env) -> Address {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&Bytes::from_slice(env, b"admin"))
+        .unwrap();
+    admin.require_auth();
+    admin
+}
 
 #[contract]
 pub struct DAOFactoryContract;
 
 #[contractimpl]
 impl DAOFactoryContract {
+    // Set the factory admin and the installed WASM each DAO and its profit
+    // token are deployed from when the factory is deployed
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        token_wasm_hash: BytesN<32>,
+        dao_wasm_hash: BytesN<32>,
+    ) {
+        let inst = env.storage().instance();
+        inst.set(&Bytes::from_slice(&env, b"admin"), &admin);
+        inst.set(&Bytes::from_slice(&env, b"paused"), &false);
+        inst.set(
+            &Bytes::from_slice(&env, b"token_wasm_hash"),
+            &token_wasm_hash,
+        );
+        inst.set(&Bytes::from_slice(&env, b"dao_wasm_hash"), &dao_wasm_hash);
+    }
+
+    // Point new deployments at newly installed profit token and DAO WASM;
+    // existing DAOs are unaffected
+    pub fn set_wasm_hashes(env: Env, token_wasm_hash: BytesN<32>, dao_wasm_hash: BytesN<32>) {
+        require_admin(&env);
+        let inst = env.storage().instance();
+        inst.set(
+            &Bytes::from_slice(&env, b"token_wasm_hash"),
+            &token_wasm_hash,
+        );
+        inst.set(&Bytes::from_slice(&env, b"dao_wasm_hash"), &dao_wasm_hash);
+    }
+
+    // Get the (profit token, DAO) WASM hashes new DAOs are deployed from
+    pub fn get_wasm_hashes(env: Env) -> (BytesN<32>, BytesN<32>) {
+        let inst = env.storage().instance();
+        (
+            inst.get(&Bytes::from_slice(&env, b"token_wasm_hash"))
+                .unwrap(),
+            inst.get(&Bytes::from_slice(&env, b"dao_wasm_hash"))
+                .unwrap(),
+        )
+    }
+
+    // Get the current factory admin
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"admin"))
+            .unwrap()
+    }
+
+    // Get the admin nominated by `propose_admin`, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"pending_admin"))
+    }
+
+    // Step 1 of an admin transfer: the current admin nominates a successor
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&Bytes::from_slice(&env, b"pending_admin"), &new_admin);
+    }
+
+    // Step 2 of an admin transfer: the nominee accepts and becomes admin
+    pub fn accept_admin(env: Env) {
+        let inst = env.storage().instance();
+        let pending: Address = inst
+            .get(&Bytes::from_slice(&env, b"pending_admin"))
+            .expect("No pending admin");
+        pending.require_auth();
+
+        inst.set(&Bytes::from_slice(&env, b"admin"), &pending);
+        inst.remove(&Bytes::from_slice(&env, b"pending_admin"));
+    }
+
+    // Stop new DAO creation (e.g. while a bug in the DAO template is fixed)
+    pub fn pause(env: Env) {
+        require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&Bytes::from_slice(&env, b"paused"), &true);
+    }
+
+    // Resume DAO creation
+    pub fn unpause(env: Env) {
+        require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&Bytes::from_slice(&env, b"paused"), &false);
+    }
+
+    // Check whether DAO creation is paused
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"paused"))
+            .unwrap_or(false)
+    }
+
+    // Charge `amount` of `asset` to every DAO creator, paid to `recipient`
+    pub fn set_creation_fee(env: Env, asset: Address, amount: i128, recipient: Address) {
+        require_admin(&env);
+        assert!(amount > 0, "Fee amount must be positive");
+
+        let fee = CreationFee {
+            asset,
+            amount,
+            recipient,
+        };
+        env.storage()
+            .instance()
+            .set(&Bytes::from_slice(&env, b"creation_fee"), &fee);
+    }
+
+    // Make DAO creation free again
+    pub fn remove_creation_fee(env: Env) {
+        require_admin(&env);
+        env.storage()
+            .instance()
+            .remove(&Bytes::from_slice(&env, b"creation_fee"));
+    }
+
+    // Get the current creation fee, if one is configured
+    pub fn get_creation_fee(env: Env) -> Option<CreationFee> {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"creation_fee"))
+    }
+
+    // Register (or replace) a named governance preset creators can pick from
+    pub fn set_preset(env: Env, preset: String, config: DaoConfig) {
+        require_admin(&env);
+
+        let inst = env.storage().instance();
+        let mut presets: Map<String, DaoConfig> = inst
+            .get(&Bytes::from_slice(&env, b"presets"))
+            .unwrap_or(Map::new(&env));
+        presets.set(preset, config);
+        inst.set(&Bytes::from_slice(&env, b"presets"), &presets);
+    }
+
+    // Remove a named preset
+    pub fn remove_preset(env: Env, preset: String) {
+        require_admin(&env);
+
+        let inst = env.storage().instance();
+        let mut presets: Map<String, DaoConfig> = inst
+            .get(&Bytes::from_slice(&env, b"presets"))
+            .unwrap_or(Map::new(&env));
+        presets.remove(preset);
+        inst.set(&Bytes::from_slice(&env, b"presets"), &presets);
+    }
+
+    // Fetch a named preset
+    pub fn get_preset(env: Env, preset: String) -> Option<DaoConfig> {
+        let inst = env.storage().instance();
+        let presets: Map<String, DaoConfig> = inst
+            .get(&Bytes::from_slice(&env, b"presets"))
+            .unwrap_or(Map::new(&env));
+
+        presets.get(preset)
+    }
+
+    // List the names of all registered presets
+    pub fn get_preset_names(env: Env) -> Vec<String> {
+        let inst = env.storage().instance();
+        let presets: Map<String, DaoConfig> = inst
+            .get(&Bytes::from_slice(&env, b"presets"))
+            .unwrap_or(Map::new(&env));
+
+        presets.keys()
+    }
+
+    // Create a DAO using one of the admin-registered presets
+    pub fn create_dao_from_preset(
+        env: Env,
+        info: Content,
+        funding_goal: i128,
+        creator: Address,
+        preset: String,
+        milestones: Vec<Milestone>,
+    ) -> (u64, Address) {
+        let config = Self::get_preset(env.clone(), preset).expect("Unknown preset");
+        Self::create_dao(env, info, funding_goal, creator, config, milestones)
+    }
+
     // Create a fresh DAO; returns its numeric ID and its on‐chain Address
     pub fn create_dao(
         env: Env,
-        name: Symbol,
-        description: Symbol,
-        funding_goal: u64,
+        info: Content,
+        funding_goal: i128,
         creator: Address,
+        config: DaoConfig,
+        milestones: Vec<Milestone>,
     ) -> (u64, Address) {
         let inst = env.storage().instance();
+        // 0. Refuse new DAOs while the factory is paused
+        let paused: bool = inst
+            .get(&Bytes::from_slice(&env, b"paused"))
+            .unwrap_or(false);
+        assert!(!paused, "Factory is paused");
+
+        // Only the creator can create a DAO in their own name
+        creator.require_auth();
+
+        // Collect the creation fee, if any, from the creator
+        let fee: Option<CreationFee> = inst.get(&Bytes::from_slice(&env, b"creation_fee"));
+        if let Some(fee) = fee {
+            TokenClient::new(&env, &fee.asset).transfer(&creator, &fee.recipient, &fee.amount);
+        }
+
         // 1. Fetch & bump DAO ID
         let next_id: u64 = inst
             .get(&Bytes::from_slice(&env, b"next_dao_id"))
             .unwrap_or(0);
 
-        // 2. Deploy a new ProfitToken for this DAO
-        // First upload the WASM
-        let token_wasm_hash: BytesN<32> = env
-            .deployer()
-            .upload_contract_wasm(Bytes::from_slice(&env, b"profit_token"));
-
-        // Deploy the token contract using with_current_contract and deploy_v2
-        let salt_token = BytesN::from_array(&env, &[0; 32]); // Use a zero salt
+        // 2. Deploy a new ProfitToken for this DAO from the installed WASM
+        let (token_wasm_hash, dao_wasm_hash) = Self::get_wasm_hashes(env.clone());
+        let salt_token = deploy_salt(&env, next_id, SALT_TAG_TOKEN);
         let token_address = env
             .deployer()
             .with_current_contract(salt_token)
             .deploy_v2(token_wasm_hash, ()); // Empty tuple for no constructor args
 
-        // 3. Deploy the DAO contract
-        let dao_wasm_hash: BytesN<32> = env
-            .deployer()
-            .upload_contract_wasm(Bytes::from_slice(&env, b"dao"));
-
-        // Deploy the DAO contract with a different salt
-        let salt_dao = BytesN::from_array(&env, &[1; 32]); // Use a different salt
+        // 3. Deploy the DAO contract with its own salt for this ID, recording
+        // this factory as the only caller allowed to initialize it
+        let salt_dao = deploy_salt(&env, next_id, SALT_TAG_DAO);
         let dao_address = env
             .deployer()
             .with_current_contract(salt_dao)
-            .deploy_v2(dao_wasm_hash, ()); // Empty tuple for no constructor args
+            .deploy_v2(dao_wasm_hash, (env.current_contract_address(),));
 
         // 4. Initialize DAO with the token contract
         // We need to update our DAO contract to accept Address instead of BytesN<32>
         let dao = DAOContractClient::new(&env, &dao_address.clone());
         dao.initialize_dao(
-            &name,
-            &description,
+            &info,
             &funding_goal,
             &creator,
             &token_address, // Pass the Address directly
+            &config,
+            &milestones,
         );
 
         // 5. Store mapping
-        let mut map: Map<u64, Address> = inst
-            .get(&Bytes::from_slice(&env, b"daos"))
-            .unwrap_or(Map::new(&env));
-        map.set(next_id, dao_address.clone());
-        inst.set(&Bytes::from_slice(&env, b"daos"), &map);
+        let persistent = env.storage().persistent();
+        persistent.set(&DataKey::Dao(next_id), &dao_address);
+        persistent.set(&DataKey::DaoId(dao_address.clone()), &next_id);
+
+        // 6. Cache the listing summary; the DAO keeps it updated afterwards
+        persistent.set(
+            &DataKey::Summary(next_id),
+            &DaoSummary {
+                id: next_id,
+                address: dao_address.clone(),
+                name: info.title,
+                creator: creator.clone(),
+                funding_goal,
+                total_raised: 0,
+                status: DaoStatus::Fundraising,
+                created_at: env.ledger().timestamp(),
+            },
+        );
+
+        // 7. Index the DAO under its creator
+        let count_key = DataKey::CreatorDaoCount(creator.clone());
+        let count: u32 = persistent.get(&count_key).unwrap_or(0);
+        persistent.set(&DataKey::CreatorDao(creator, count), &next_id);
+        persistent.set(&count_key, &(count + 1));
 
-        // 6. Bump next_id
+        // 8. Bump next_id
         inst.set(&Bytes::from_slice(&env, b"next_dao_id"), &(next_id + 1));
 
         (next_id, dao_address)
     }
 
+    // Called by a DAO deployed from this factory whenever its status or raise changes
+    pub fn update_dao_summary(env: Env, dao: Address, status: DaoStatus, total_raised: i128) {
+        dao.require_auth();
+
+        let persistent = env.storage().persistent();
+        let dao_id: u64 = persistent.get(&DataKey::DaoId(dao)).expect("Unknown DAO");
+
+        let mut summary: DaoSummary = persistent.get(&DataKey::Summary(dao_id)).unwrap();
+        summary.status = status;
+        summary.total_raised = total_raised;
+        persistent.set(&DataKey::Summary(dao_id), &summary);
+    }
+
+    // Fetch the cached summary of a DAO by its ID
+    pub fn get_dao_summary(env: Env, dao_id: u64) -> Option<DaoSummary> {
+        env.storage().persistent().get(&DataKey::Summary(dao_id))
+    }
+
+    // Fetch cached summaries for IDs `start..start + limit` (limit capped at MAX_PAGE_SIZE)
+    pub fn get_dao_summaries(env: Env, start: u64, limit: u64) -> Vec<DaoSummary> {
+        let inst = env.storage().instance();
+        let persistent = env.storage().persistent();
+
+        let total: u64 = inst
+            .get(&Bytes::from_slice(&env, b"next_dao_id"))
+            .unwrap_or(0);
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE as u64))
+            .min(total);
+
+        let mut result: Vec<DaoSummary> = Vec::new(&env);
+        for id in start..end {
+            if let Some(summary) = persistent.get(&DataKey::Summary(id)) {
+                result.push_back(summary);
+            }
+        }
+
+        result
+    }
+
     // Fetch the on‐chain address of a DAO by its ID
     pub fn get_dao(env: Env, dao_id: u64) -> Option<Address> {
-        let inst = env.storage().instance();
-        let map: Map<u64, Address> = inst
-            .get(&Bytes::from_slice(&env, b"daos"))
-            .unwrap_or(Map::new(&env));
+        env.storage().persistent().get(&DataKey::Dao(dao_id))
+    }
+
+    // Fetch the ID of a DAO deployed by this factory, or None for any other address
+    pub fn get_dao_id(env: Env, dao: Address) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::DaoId(dao))
+    }
 
-        map.get(dao_id)
+    // Compute the address a DAO with this ID is (or will be) deployed at
+    pub fn predict_dao_address(env: Env, dao_id: u64) -> Address {
+        env.deployer()
+            .with_current_contract(deploy_salt(&env, dao_id, SALT_TAG_DAO))
+            .deployed_address()
     }
 
     // Get the total number of DAOs
@@ -90,23 +422,18 @@ impl DAOFactoryContract {
     // Get DAOs with pagination
     pub fn get_daos_paginated(env: Env, start: u64, limit: u64) -> Vec<(u64, Address)> {
         let inst = env.storage().instance();
-        let map: Map<u64, Address> = inst
-            .get(&Bytes::from_slice(&env, b"daos"))
-            .unwrap_or(Map::new(&env));
+        let persistent = env.storage().persistent();
 
         let total = inst
             .get(&Bytes::from_slice(&env, b"next_dao_id"))
             .unwrap_or(0);
         let mut result: Vec<(u64, Address)> = Vec::new(&env);
 
-        let end = if start + limit > total {
-            total
-        } else {
-            start + limit
-        };
+        let limit = limit.min(MAX_PAGE_SIZE as u64);
+        let end = start.saturating_add(limit).min(total);
 
         for i in start..end {
-            if let Some(dao) = map.get(i) {
+            if let Some(dao) = persistent.get(&DataKey::Dao(i)) {
                 result.push_back((i, dao));
             }
         }
@@ -114,46 +441,69 @@ impl DAOFactoryContract {
         result
     }
 
-    // Fetch all DAOs created by a specific address
-    pub fn get_daos_by_creator(env: Env, creator: Address) -> Vec<(u64, Address)> {
-        let inst = env.storage().instance();
-        let map: Map<u64, Address> = inst
-            .get(&Bytes::from_slice(&env, b"daos"))
-            .unwrap_or(Map::new(&env));
+    // Number of DAOs created by a specific address
+    pub fn get_dao_count_by_creator(env: Env, creator: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CreatorDaoCount(creator))
+            .unwrap_or(0)
+    }
 
-        let total = inst
-            .get(&Bytes::from_slice(&env, b"next_dao_id"))
-            .unwrap_or(0);
+    // Fetch DAOs created by a specific address, `limit` entries from position `start`
+    pub fn get_daos_by_creator(
+        env: Env,
+        creator: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(u64, Address)> {
+        let persistent = env.storage().persistent();
+        let count = Self::get_dao_count_by_creator(env.clone(), creator.clone());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
         let mut result: Vec<(u64, Address)> = Vec::new(&env);
 
-        for i in 0..total {
-            if let Some(dao_address) = map.get(i) {
-                // Check if this DAO was created by the specified creator
-                let dao = DAOContractClient::new(&env, &dao_address);
-                if dao.get_creator() == creator {
-                    result.push_back((i, dao_address));
-                }
+        for i in start..end {
+            let id: u64 = persistent
+                .get(&DataKey::CreatorDao(creator.clone(), i))
+                .unwrap();
+            if let Some(dao) = persistent.get(&DataKey::Dao(id)) {
+                result.push_back((id, dao));
             }
         }
 
         result
     }
 
-    // Fetch the list of all DAOs
-    pub fn get_all_daos(env: Env) -> Vec<Address> {
+    // List DAOs starting at ID `cursor`, at most `limit` (capped at MAX_PAGE_SIZE)
+    // IDs per call. Returns the entries plus the cursor for the next page, or
+    // None once the end is reached.
+    #[allow(clippy::type_complexity)]
+    pub fn list_daos(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+    ) -> (Vec<(u64, Address, String, DaoStatus)>, Option<u64>) {
         let inst = env.storage().instance();
-        let map: Map<u64, Address> = inst
-            .get(&Bytes::from_slice(&env, b"daos"))
-            .unwrap_or(Map::new(&env));
+        let persistent = env.storage().persistent();
 
-        let mut daos: Vec<Address> = Vec::new(&env);
-        for i in 0..map.len() {
-            // Convert u32 to u64
-            if let Some(dao) = map.get(i.into()) {
-                // Use push_back not push
-                daos.push_back(dao);
+        let total: u64 = inst
+            .get(&Bytes::from_slice(&env, b"next_dao_id"))
+            .unwrap_or(0);
+        let end = cursor
+            .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+            .min(total);
+
+        let mut result: Vec<(u64, Address, String, DaoStatus)> = Vec::new(&env);
+        for id in cursor..end {
+            // Skip missing IDs instead of ending the listing early
+            if let Some(summary) = persistent.get::<_, DaoSummary>(&DataKey::Summary(id)) {
+                result.push_back((id, summary.address, summary.name, summary.status));
             }
         }
-        daos
+
+        let next = if end < total { Some(end) } else { None };
+        (result, next)
     }
 }
+
+#[cfg(test)]
+mod test;
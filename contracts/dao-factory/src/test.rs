@@ -0,0 +1,456 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use dao::{ContentBody, DAOContract};
+use profit_token::ProfitTokenContract;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, Ledger},
+    token::StellarAssetClient,
+    vec,
+    xdr::{ContractDataDurability, LedgerKey, LedgerKeyContractData, ScAddress, ScVal},
+    Symbol,
+};
+use std::rc::Rc;
+
+struct Setup<'a> {
+    env: Env,
+    factory: DAOFactoryContractClient<'a>,
+    admin: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // There is no WASM build of the DAO and profit token in unit tests. The
+    // host runs a contract natively when its code is the empty WASM, so the
+    // factory is pointed at that, and `stage_native` registers the native
+    // contracts for each deployment.
+    let native = env.deployer().upload_contract_wasm(Bytes::new(&env));
+    let admin = Address::generate(&env);
+    let factory = env.register(DAOFactoryContract, (admin.clone(), native.clone(), native));
+
+    Setup {
+        factory: DAOFactoryContractClient::new(&env, &factory),
+        admin,
+        env,
+    }
+}
+
+// Register the native token and DAO at the addresses DAO `dao_id` deploys to,
+// then drop their instances so that `create_dao` can deploy over them
+fn stage_native(s: &Setup, dao_id: u64) {
+    let env = &s.env;
+    let token = env.as_contract(&s.factory.address, || {
+        env.deployer()
+            .with_current_contract(deploy_salt(env, dao_id, SALT_TAG_TOKEN))
+            .deployed_address()
+    });
+    let dao = s.factory.predict_dao_address(&dao_id);
+    env.register_at(&token, ProfitTokenContract, ());
+    env.register_at(&dao, DAOContract, (s.factory.address.clone(),));
+
+    for contract in [token, dao] {
+        let key = LedgerKey::ContractData(LedgerKeyContractData {
+            contract: ScAddress::from(&contract),
+            key: ScVal::LedgerKeyContractInstance,
+            durability: ContractDataDurability::Persistent,
+        });
+        env.host()
+            .with_mut_storage(|storage| storage.del(&Rc::new(key), &env.host().budget_cloned()))
+            .unwrap();
+    }
+}
+
+fn info(env: &Env, title: &str) -> Content {
+    Content {
+        title: String::from_str(env, title),
+        body: ContentBody::None,
+        content_hash: BytesN::from_array(env, &[0; 32]),
+    }
+}
+
+fn config(env: &Env) -> DaoConfig {
+    dao::testutils::config(env, &Address::generate(env))
+}
+
+fn create(s: &Setup, creator: &Address, title: &str) -> (u64, Address) {
+    create_with(s, creator, title, &config(&s.env))
+}
+
+fn create_with(s: &Setup, creator: &Address, title: &str, config: &DaoConfig) -> (u64, Address) {
+    stage_native(s, s.factory.get_dao_count());
+    s.factory.create_dao(
+        &info(&s.env, title),
+        &1_000,
+        creator,
+        config,
+        &Vec::new(&s.env),
+    )
+}
+
+#[test]
+fn second_dao_deploys_to_its_predicted_address() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    let predicted = (
+        s.factory.predict_dao_address(&0),
+        s.factory.predict_dao_address(&1),
+    );
+
+    let first = create(&s, &creator, "First");
+    let second = create(&s, &creator, "Second");
+
+    assert_eq!(first, (0, predicted.0));
+    assert_eq!(second, (1, predicted.1));
+    assert_ne!(first.1, second.1);
+    assert_eq!(s.factory.get_dao(&1), Some(second.1.clone()));
+    let dao = DAOContractClient::new(&s.env, &second.1);
+    assert_eq!(dao.get_name(), String::from_str(&s.env, "Second"));
+    assert_eq!(dao.get_factory(), s.factory.address);
+}
+
+#[test]
+fn admin_updates_wasm_hashes() {
+    let s = setup();
+    let token = BytesN::from_array(&s.env, &[1; 32]);
+    let dao = BytesN::from_array(&s.env, &[2; 32]);
+
+    s.factory.set_wasm_hashes(&token, &dao);
+    assert_eq!(
+        s.env.auths()[0].0,
+        s.admin,
+        "only the admin may change deployed code"
+    );
+    assert_eq!(s.factory.get_wasm_hashes(), (token, dao));
+}
+
+#[test]
+fn admin_transfer_takes_two_steps() {
+    let s = setup();
+    let successor = Address::generate(&s.env);
+
+    s.factory.propose_admin(&successor);
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    assert_eq!(s.factory.get_admin(), s.admin);
+    assert_eq!(s.factory.get_pending_admin(), Some(successor.clone()));
+
+    s.factory.accept_admin();
+    assert_eq!(
+        s.env.auths()[0].0,
+        successor,
+        "the nominee must accept the role"
+    );
+    assert_eq!(s.factory.get_admin(), successor);
+    assert_eq!(s.factory.get_pending_admin(), None);
+}
+
+#[test]
+fn accept_admin_without_nominee_fails() {
+    let s = setup();
+    assert!(s.factory.try_accept_admin().is_err());
+}
+
+#[test]
+fn pause_blocks_creation_but_not_reads() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    let (id, dao) = create(&s, &creator, "Before");
+
+    s.factory.pause();
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    assert!(s.factory.is_paused());
+
+    stage_native(&s, 1);
+    let result = s.factory.try_create_dao(
+        &info(&s.env, "During"),
+        &1_000,
+        &creator,
+        &config(&s.env),
+        &Vec::new(&s.env),
+    );
+    assert!(result.is_err());
+    assert_eq!(s.factory.get_dao_count(), 1);
+    assert_eq!(s.factory.get_dao(&id), Some(dao));
+
+    s.factory.unpause();
+    assert!(!s.factory.is_paused());
+    assert_eq!(create(&s, &creator, "After").0, 1);
+}
+
+#[test]
+fn create_dao_requires_creator_auth() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    create(&s, &creator, "Mine");
+
+    let (signer, invocation) = s.env.auths()[0].clone();
+    assert_eq!(signer, creator);
+    match invocation.function {
+        AuthorizedFunction::Contract((contract, function, _)) => {
+            assert_eq!(contract, s.factory.address);
+            assert_eq!(function, Symbol::new(&s.env, "create_dao"));
+        }
+        _ => panic!("expected a contract call"),
+    }
+}
+
+#[test]
+fn creation_fee_is_paid_to_recipient() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    let recipient = Address::generate(&s.env);
+    let asset = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+    StellarAssetClient::new(&s.env, &asset).mint(&creator, &150);
+    let token = TokenClient::new(&s.env, &asset);
+
+    s.factory.set_creation_fee(&asset, &100, &recipient);
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    assert_eq!(
+        s.factory.get_creation_fee(),
+        Some(CreationFee {
+            asset: asset.clone(),
+            amount: 100,
+            recipient: recipient.clone(),
+        })
+    );
+
+    create(&s, &creator, "Paid");
+    assert_eq!(token.balance(&creator), 50);
+    assert_eq!(token.balance(&recipient), 100);
+
+    // Not enough left for a second fee, so nothing is created
+    stage_native(&s, 1);
+    let result = s.factory.try_create_dao(
+        &info(&s.env, "Unpaid"),
+        &1_000,
+        &creator,
+        &config(&s.env),
+        &Vec::new(&s.env),
+    );
+    assert!(result.is_err());
+    assert_eq!(s.factory.get_dao_count(), 1);
+
+    s.factory.remove_creation_fee();
+    assert_eq!(s.factory.get_creation_fee(), None);
+    create(&s, &creator, "Free");
+    assert_eq!(token.balance(&creator), 50);
+}
+
+#[test]
+fn creation_fee_must_be_positive() {
+    let s = setup();
+    let asset = Address::generate(&s.env);
+    let recipient = Address::generate(&s.env);
+    assert!(s
+        .factory
+        .try_set_creation_fee(&asset, &0, &recipient)
+        .is_err());
+}
+
+#[test]
+fn daos_are_indexed_by_creator() {
+    let s = setup();
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+
+    let a0 = create(&s, &alice, "A0");
+    let b0 = create(&s, &bob, "B0");
+    let a1 = create(&s, &alice, "A1");
+    let a2 = create(&s, &alice, "A2");
+
+    assert_eq!(s.factory.get_dao_count_by_creator(&alice), 3);
+    assert_eq!(s.factory.get_dao_count_by_creator(&bob), 1);
+    assert_eq!(
+        s.factory.get_daos_by_creator(&alice, &0, &10),
+        vec![&s.env, a0, a1.clone(), a2.clone()]
+    );
+    assert_eq!(
+        s.factory.get_daos_by_creator(&alice, &1, &2),
+        vec![&s.env, a1, a2]
+    );
+    assert_eq!(
+        s.factory.get_daos_by_creator(&bob, &0, &10),
+        vec![&s.env, b0]
+    );
+    assert!(s.factory.get_daos_by_creator(&bob, &1, &10).is_empty());
+    assert!(s
+        .factory
+        .get_daos_by_creator(&alice, &u32::MAX, &u32::MAX)
+        .is_empty());
+}
+
+#[test]
+fn list_daos_pages_with_a_cursor() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    for title in ["D0", "D1", "D2"] {
+        create(&s, &creator, title);
+    }
+
+    let (page, next) = s.factory.list_daos(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(
+        page.get(1).unwrap(),
+        (
+            1,
+            s.factory.predict_dao_address(&1),
+            String::from_str(&s.env, "D1"),
+            DaoStatus::Fundraising
+        )
+    );
+    assert_eq!(next, Some(2));
+
+    let (page, next) = s.factory.list_daos(&2, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().0, 2);
+    assert_eq!(next, None);
+}
+
+#[test]
+fn list_daos_caps_limit_and_survives_overflow() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    for i in 0..MAX_PAGE_SIZE + 1 {
+        stage_native(&s, i as u64);
+        s.factory.create_dao(
+            &info(&s.env, "DAO"),
+            &1_000,
+            &creator,
+            &config(&s.env),
+            &Vec::new(&s.env),
+        );
+    }
+
+    let (page, next) = s.factory.list_daos(&0, &u32::MAX);
+    assert_eq!(page.len(), MAX_PAGE_SIZE);
+    assert_eq!(next, Some(MAX_PAGE_SIZE as u64));
+
+    let (page, next) = s.factory.list_daos(&u64::MAX, &u32::MAX);
+    assert!(page.is_empty());
+    assert_eq!(next, None);
+    assert!(s
+        .factory
+        .get_daos_paginated(&u64::MAX, &u64::MAX)
+        .is_empty());
+}
+
+#[test]
+fn summaries_follow_the_dao_lifecycle() {
+    let s = setup();
+    s.env.ledger().set_timestamp(500);
+    let creator = Address::generate(&s.env);
+    let investor = Address::generate(&s.env);
+    let asset = s
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&s.env))
+        .address();
+    StellarAssetClient::new(&s.env, &asset).mint(&investor, &1_000);
+    let mut config = config(&s.env);
+    config.accepted_asset = asset;
+
+    create(&s, &creator, "Listed");
+    let (id, address) = create_with(&s, &creator, "Funded", &config);
+    let expected = DaoSummary {
+        id,
+        address: address.clone(),
+        name: String::from_str(&s.env, "Funded"),
+        creator,
+        funding_goal: 1_000,
+        total_raised: 0,
+        status: DaoStatus::Fundraising,
+        created_at: 500,
+    };
+    assert_eq!(s.factory.get_dao_summary(&id), Some(expected.clone()));
+
+    // The DAO pushes its new raise and status to the factory
+    DAOContractClient::new(&s.env, &address).invest(&investor, &1_000);
+    let summaries = s.factory.get_dao_summaries(&0, &10);
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(
+        summaries.get(1).unwrap(),
+        DaoSummary {
+            total_raised: 1_000,
+            status: DaoStatus::Funded,
+            ..expected
+        }
+    );
+    assert_eq!(s.factory.get_dao_summaries(&1, &1).len(), 1);
+    assert!(s.factory.get_dao_summaries(&u64::MAX, &u64::MAX).is_empty());
+}
+
+#[test]
+fn unknown_dao_cannot_update_a_summary() {
+    let s = setup();
+    create(&s, &Address::generate(&s.env), "Listed");
+    let stranger = Address::generate(&s.env);
+
+    let result = s
+        .factory
+        .try_update_dao_summary(&stranger, &DaoStatus::Funded, &1_000);
+    assert!(result.is_err());
+    assert_eq!(
+        s.factory.get_dao_summary(&0).unwrap().status,
+        DaoStatus::Fundraising
+    );
+}
+
+#[test]
+fn presets_are_managed_by_the_admin() {
+    let s = setup();
+    let standard = String::from_str(&s.env, "pharmacy-standard");
+    let mut strict = config(&s.env);
+    strict.quorum_bps = 5_000;
+
+    s.factory.set_preset(&standard, &config(&s.env));
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    s.factory
+        .set_preset(&String::from_str(&s.env, "strict"), &strict);
+    assert_eq!(s.factory.get_preset_names().len(), 2);
+
+    // Registering a name again replaces the preset
+    s.factory.set_preset(&standard, &strict);
+    assert_eq!(s.factory.get_preset(&standard), Some(strict));
+
+    s.factory.remove_preset(&standard);
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    assert_eq!(s.factory.get_preset(&standard), None);
+    assert_eq!(
+        s.factory.get_preset_names(),
+        vec![&s.env, String::from_str(&s.env, "strict")]
+    );
+}
+
+#[test]
+fn dao_created_from_preset_uses_its_config() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    let preset = String::from_str(&s.env, "pharmacy-standard");
+    let mut config = config(&s.env);
+    config.voting_period = 777;
+    s.factory.set_preset(&preset, &config);
+
+    stage_native(&s, 0);
+    let (_, dao) = s.factory.create_dao_from_preset(
+        &info(&s.env, "Pharmacy"),
+        &1_000,
+        &creator,
+        &preset,
+        &Vec::new(&s.env),
+    );
+    assert_eq!(DAOContractClient::new(&s.env, &dao).get_config(), config);
+
+    stage_native(&s, 1);
+    let result = s.factory.try_create_dao_from_preset(
+        &info(&s.env, "Unknown"),
+        &1_000,
+        &creator,
+        &String::from_str(&s.env, "missing"),
+        &Vec::new(&s.env),
+    );
+    assert!(result.is_err());
+}
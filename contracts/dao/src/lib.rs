@@ -1,31 +1,671 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, symbol_short, vec, Address, Bytes, Env, IntoVal, Map, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error,
+    symbol_short, token::TokenClient, Address, Bytes, BytesN, Env, Map, String, Vec,
 };
 
 // Import the profit‐token client
 use profit_token::ProfitTokenContractClient;
 
+// Typed failures of DAO accounting. Amounts are i128 throughout, matching the
+// profit token and SEP-41 assets.
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    Overflow = 1,
+    DivisionByZero = 2,
+}
+
+fn add(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+fn sub(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_sub(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+fn mul(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_mul(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+// a * b / c without silently wrapping the intermediate product
+fn mul_div(env: &Env, a: i128, b: i128, c: i128) -> i128 {
+    if c == 0 {
+        panic_with_error!(env, Error::DivisionByZero);
+    }
+    mul(env, a, b) / c
+}
+
+// Timestamp arithmetic: `time` plus a duration in seconds
+fn add_time(env: &Env, time: u64, seconds: u64) -> u64 {
+    time.checked_add(seconds)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+// Funding stage of a DAO, as shown on listings
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DaoStatus {
+    Fundraising,
+    Funded,
+    // Investors voted to refund the treasury after a missed milestone
+    Refunding,
+}
+
+// A tranche of the raise the creator can draw once investors approve it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub description: String,
+    pub amount: i128,
+    // Ledger timestamp by which the tranche should have been released
+    pub due_date: u64,
+}
+
+// Basis-point denominator for quorum and approval thresholds
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+// Governance and raise parameters chosen when the DAO is created
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DaoConfig {
+    // Seconds between a proposal's creation and the start of voting
+    pub voting_delay: u64,
+    // Seconds a proposal stays open for voting
+    pub voting_period: u64,
+    // Seconds a queued proposal must wait before it can be executed
+    pub timelock_delay: u64,
+    // Seconds after its ETA during which a queued proposal can still be executed
+    pub grace_period: u64,
+    // Share of the token supply that must vote, in basis points
+    pub quorum_bps: u32,
+    // Share of the votes cast that must be in favour, in basis points
+    pub approval_threshold_bps: u32,
+    // Profit-token balance required to create a proposal
+    pub proposal_threshold: i128,
+    // Open proposals a single proposer may have at once
+    pub max_active_proposals: u32,
+    // Seconds after creation during which investments are accepted
+    pub funding_period: u64,
+    // Smallest accepted ticket
+    pub min_investment: i128,
+    // Most a single investor may put in over the whole raise
+    pub max_investment: i128,
+    // Most the DAO will raise in total; must be at least the funding goal
+    pub hard_cap: i128,
+    // Seconds after each investment before its shares can be transferred
+    pub lockup_period: u64,
+    // SEP-41 asset investors pay in
+    pub accepted_asset: Address,
+    // Profit distribution contract allowed to reinvest investors' payouts
+    pub profit_distributor: Address,
+    // Whether the creator may freeze and claw back shares. Fixed at creation,
+    // so investors know the terms before they buy in.
+    pub compliance_enabled: bool,
+    pub token_name: String,
+    pub token_symbol: String,
+}
+
+// Everything an investor needs to know about the raise, in one call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaiseTerms {
+    pub accepted_asset: Address,
+    pub funding_goal: i128,
+    pub hard_cap: i128,
+    pub min_investment: i128,
+    pub max_investment: i128,
+    pub funding_deadline: u64,
+    pub total_raised: i128,
+}
+
+// Longest accepted title, in bytes
+pub const MAX_TITLE_LEN: u32 = 128;
+// Longest accepted inline body, in bytes; anything larger belongs off-chain behind a URI
+pub const MAX_BODY_LEN: u32 = 4096;
+// Longest accepted vote reason, in bytes
+pub const MAX_REASON_LEN: u32 = 280;
+// Longest accepted body URI, in bytes
+pub const MAX_URI_LEN: u32 = 256;
+
+// Long-form text for a DAO or proposal: stored inline, or linked off-chain
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContentBody {
+    None,
+    Inline(Bytes),
+    // An ipfs:// or https:// URI
+    Uri(String),
+}
+
+// Title plus optional long-form body and the hash of any off-chain document
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Content {
+    pub title: String,
+    pub body: ContentBody,
+    // Hash of the off-chain document, all zeroes when there is none
+    pub content_hash: BytesN<32>,
+}
+
+// Lifecycle of a proposal. Cancelled, Queued and Executed are recorded when they
+// happen; the other states follow from the clock and the tally.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Pending,
+    Active,
+    Defeated,
+    Succeeded,
+    Queued,
+    Executed,
+    Cancelled,
+    Expired,
+}
+
+// A voter's choice on a proposal
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VoteChoice {
+    For,
+    Against,
+    // Counts toward quorum but not toward approval
+    Abstain,
+}
+
+// A cast vote, kept per (proposal, voter)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ballot {
+    pub choice: VoteChoice,
+    pub weight: i128,
+    // Empty when the voter gave no reason
+    pub reason: String,
+}
+
+// Terms of a creator or team share, as proposed to investors
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingGrant {
+    pub beneficiary: Address,
+    // Profit tokens vested over the whole schedule
+    pub amount: i128,
+    // Seconds after the grant before anything vests
+    pub cliff: u64,
+    // Seconds after the grant until everything has vested
+    pub duration: u64,
+}
+
+// A granted vesting schedule and how much of it has been claimed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub grant: VestingGrant,
+    pub start: u64,
+    pub claimed: i128,
+}
+
+// Tokens vested under `schedule` at time `now`: nothing before the cliff, then linear
+fn vested_amount(env: &Env, schedule: &VestingSchedule, now: u64) -> i128 {
+    let grant = &schedule.grant;
+    let elapsed = now.saturating_sub(schedule.start);
+    if elapsed < grant.cliff {
+        0
+    } else if elapsed >= grant.duration {
+        grant.amount
+    } else {
+        mul_div(env, grant.amount, elapsed as i128, grant.duration as i128)
+    }
+}
+
+// What executing a proposal does
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    // Signalling only; nothing moves
+    None,
+    // Pay an amount of the accepted asset from the treasury to a recipient
+    Payout(Address, i128),
+    // Release the tranche of the milestone with this index to the creator
+    ReleaseMilestone(u32),
+    // Return the remaining treasury to investors after a missed milestone
+    Refund,
+    // Allocate a vesting profit-token share to a creator or team member
+    GrantVesting(VestingGrant),
+    // Mint profit tokens to several holders at once
+    MintShares(Vec<(Address, i128)>),
+    // Cap the profit-token supply at this amount
+    SetShareMaxSupply(i128),
+    // Lift the profit-token supply cap
+    RemoveShareMaxSupply,
+    // Hand the profit token's admin role to another contract, e.g. a migrated DAO
+    SetShareAdmin(Address),
+}
+
+// Everything stored about a single proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub content: Content,
+    pub action: ProposalAction,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    pub votes_abstain: i128,
+    // Votes and supply are counted as of `start`; voting opens once the clock
+    // has moved past it, so that snapshot can no longer change, and closes at
+    // `deadline`
+    pub start: u64,
+    pub deadline: u64,
+    // Earliest execution time once queued, 0 before that
+    pub eta: u64,
+    pub status: ProposalStatus,
+}
+
+// Records that grow with the DAO's history, each kept in its own persistent
+// entry rather than in instance storage, which is loaded on every call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // Proposal ID -> Proposal
+    Proposal(u64),
+    // (proposal ID, voter) -> Ballot
+    Ballot(u64, Address),
+    // Proposer -> IDs of their proposals that may still be open
+    ActiveProposals(Address),
+    // Present for each address on the creator-managed allowlist
+    Allowed(Address),
+    // n-th completed profit distribution -> (timestamp, amount)
+    Distribution(u32),
+}
+
+// Reject empty or oversized text and URIs with an unsupported scheme
+fn validate_content(content: &Content) {
+    let title_len = content.title.len();
+    assert!(
+        title_len > 0 && title_len <= MAX_TITLE_LEN,
+        "Title must be 1-128 bytes"
+    );
+
+    match &content.body {
+        ContentBody::Inline(body) => {
+            assert!(body.len() <= MAX_BODY_LEN, "Body too long");
+        }
+        ContentBody::Uri(uri) => {
+            let len = uri.len();
+            assert!(len <= MAX_URI_LEN, "URI too long");
+            let mut buf = [0u8; MAX_URI_LEN as usize];
+            uri.copy_into_slice(&mut buf[..len as usize]);
+            let uri = &buf[..len as usize];
+            assert!(
+                uri.starts_with(b"ipfs://") || uri.starts_with(b"https://"),
+                "URI must be ipfs:// or https://"
+            );
+        }
+        ContentBody::None => {}
+    }
+}
+
+fn load_proposal(env: &Env, proposal_id: u64) -> Proposal {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Proposal(proposal_id))
+        .expect("Proposal does not exist")
+}
+
+// Count the proposer's proposals that are still open for voting, forgetting closed ones
+fn count_active_proposals(env: &Env, proposer: &Address) -> u32 {
+    let persistent = env.storage().persistent();
+    let key = DataKey::ActiveProposals(proposer.clone());
+    let ids: Vec<u64> = persistent.get(&key).unwrap_or(Vec::new(env));
+
+    let mut active: Vec<u64> = Vec::new(env);
+    for id in ids.iter() {
+        let state = proposal_state(env, &load_proposal(env, id));
+        if state == ProposalStatus::Pending || state == ProposalStatus::Active {
+            active.push_back(id);
+        }
+    }
+
+    let count = active.len();
+    if active.is_empty() {
+        persistent.remove(&key);
+    } else if active.len() != ids.len() {
+        persistent.set(&key, &active);
+    }
+    count
+}
+
+// Check a proposal action can be proposed in the DAO's current state
+fn validate_action(env: &Env, action: &ProposalAction) {
+    let inst = env.storage().instance();
+    let milestones: Vec<Milestone> = inst.get(&Bytes::from_slice(env, b"milestones")).unwrap();
+    let next: u32 = inst
+        .get(&Bytes::from_slice(env, b"next_milestone"))
+        .unwrap();
+
+    match action {
+        ProposalAction::None => {}
+        ProposalAction::Payout(_, amount) => {
+            assert!(*amount > 0, "Payout amount must be positive");
+        }
+        ProposalAction::ReleaseMilestone(index) => {
+            assert!(*index == next, "Only the next milestone can be released");
+            assert!(*index < milestones.len(), "Milestone does not exist");
+        }
+        ProposalAction::Refund => {
+            // Only once the next unreleased milestone is overdue
+            let missed = next < milestones.len()
+                && env.ledger().timestamp() > milestones.get_unchecked(next).due_date;
+            assert!(missed, "No milestone has been missed");
+        }
+        ProposalAction::GrantVesting(grant) => {
+            // New shares would dilute the fixed refund pool
+            let refunding: bool = inst.get(&Bytes::from_slice(env, b"refunding")).unwrap();
+            assert!(!refunding, "DAO is refunding investors");
+            assert!(grant.amount > 0, "Vesting amount must be positive");
+            assert!(
+                grant.duration > 0 && grant.cliff <= grant.duration,
+                "Invalid vesting schedule"
+            );
+        }
+        ProposalAction::MintShares(recipients) => {
+            let refunding: bool = inst.get(&Bytes::from_slice(env, b"refunding")).unwrap();
+            assert!(!refunding, "DAO is refunding investors");
+            assert!(!recipients.is_empty(), "No recipients to mint to");
+            for (_, amount) in recipients.iter() {
+                assert!(amount > 0, "Mint amount must be positive");
+            }
+        }
+        ProposalAction::SetShareMaxSupply(max_supply) => {
+            assert!(*max_supply > 0, "Max supply must be positive");
+        }
+        ProposalAction::RemoveShareMaxSupply | ProposalAction::SetShareAdmin(_) => {}
+    }
+}
+
+// Whether a closed vote met quorum and the approval threshold
+fn proposal_passed(env: &Env, proposal: &Proposal) -> bool {
+    let inst = env.storage().instance();
+    let config: DaoConfig = inst.get(&Bytes::from_slice(env, b"config")).unwrap();
+    let votes_for = proposal.votes_for;
+    let decisive = add(env, votes_for, proposal.votes_against);
+    let turnout = add(env, decisive, proposal.votes_abstain);
+
+    // Quorum is measured against the whole token supply
+    let token_address: Address = inst
+        .get(&Bytes::from_slice(env, b"token_contract_id"))
+        .unwrap();
+    let supply =
+        ProfitTokenContractClient::new(env, &token_address).get_past_total_supply(&proposal.start);
+    let quorum_reached =
+        mul(env, turnout, BPS_DENOMINATOR as i128) >= mul(env, supply, config.quorum_bps as i128);
+
+    quorum_reached
+        && votes_for > 0
+        && mul(env, votes_for, BPS_DENOMINATOR as i128)
+            >= mul(env, decisive, config.approval_threshold_bps as i128)
+}
+
+// Current lifecycle state of a proposal
+fn proposal_state(env: &Env, proposal: &Proposal) -> ProposalStatus {
+    let now = env.ledger().timestamp();
+    match proposal.status {
+        ProposalStatus::Cancelled | ProposalStatus::Executed => return proposal.status,
+        ProposalStatus::Queued => {
+            let config: DaoConfig = env
+                .storage()
+                .instance()
+                .get(&Bytes::from_slice(env, b"config"))
+                .unwrap();
+            return if now > add_time(env, proposal.eta, config.grace_period) {
+                ProposalStatus::Expired
+            } else {
+                ProposalStatus::Queued
+            };
+        }
+        _ => {}
+    }
+
+    if now <= proposal.start {
+        ProposalStatus::Pending
+    } else if now < proposal.deadline {
+        ProposalStatus::Active
+    } else if proposal_passed(env, proposal) {
+        ProposalStatus::Succeeded
+    } else {
+        ProposalStatus::Defeated
+    }
+}
+
+// Proposals currently sitting in their timelock or awaiting execution,
+// forgetting any that were executed, cancelled or expired since
+fn queued_proposals(env: &Env) -> Vec<Proposal> {
+    let inst = env.storage().instance();
+    let ids: Vec<u64> = inst
+        .get(&Bytes::from_slice(env, b"queued_proposals"))
+        .unwrap_or(Vec::new(env));
+
+    let mut still_queued: Vec<u64> = Vec::new(env);
+    let mut result: Vec<Proposal> = Vec::new(env);
+    for id in ids.iter() {
+        let proposal = load_proposal(env, id);
+        if proposal_state(env, &proposal) == ProposalStatus::Queued {
+            still_queued.push_back(id);
+            result.push_back(proposal);
+        }
+    }
+
+    inst.set(&Bytes::from_slice(env, b"queued_proposals"), &still_queued);
+    result
+}
+
+// Treasury funds already promised to queued payout proposals
+fn committed_funds(env: &Env) -> i128 {
+    let milestones: Vec<Milestone> = env
+        .storage()
+        .instance()
+        .get(&Bytes::from_slice(env, b"milestones"))
+        .unwrap();
+
+    let mut committed = 0i128;
+    for proposal in queued_proposals(env).iter() {
+        match proposal.action {
+            ProposalAction::Payout(_, amount) => committed = add(env, committed, amount),
+            ProposalAction::ReleaseMilestone(index) => {
+                committed = add(env, committed, milestones.get_unchecked(index).amount)
+            }
+            _ => {}
+        }
+    }
+    committed
+}
+
+// Milestone tranches still owed to the creator and not yet queued for release.
+// Payouts may not spend these, or the milestones could never be paid.
+fn unqueued_tranches(env: &Env) -> i128 {
+    let inst = env.storage().instance();
+    let milestones: Vec<Milestone> = inst.get(&Bytes::from_slice(env, b"milestones")).unwrap();
+    let next: u32 = inst
+        .get(&Bytes::from_slice(env, b"next_milestone"))
+        .unwrap();
+
+    let mut queued: Vec<u32> = Vec::new(env);
+    for proposal in queued_proposals(env).iter() {
+        if let ProposalAction::ReleaseMilestone(index) = proposal.action {
+            queued.push_back(index);
+        }
+    }
+
+    let mut reserved = 0i128;
+    for index in next..milestones.len() {
+        if !queued.contains(index) {
+            reserved = add(env, reserved, milestones.get_unchecked(index).amount);
+        }
+    }
+    reserved
+}
+
+fn save_proposal(env: &Env, proposal: &Proposal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Proposal(proposal.id), proposal);
+}
+
+// Who may invest in (and receive shares of) the DAO
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvestorGate {
+    // Anyone
+    Open,
+    // Only addresses the creator has added to the allowlist
+    Allowlist,
+    // Only addresses the given attestation contract reports as verified
+    Attestation(Address),
+}
+
+// External KYC / attestation provider used by `InvestorGate::Attestation`
+#[contractclient(name = "AttestationClient")]
+pub trait AttestationInterface {
+    fn is_verified(env: Env, account: Address) -> bool;
+}
+
+// Load the creator and require its authorization
+fn require_creator(env: &Env) -> Address {
+    let creator: Address = env
+        .storage()
+        .instance()
+        .get(&Bytes::from_slice(env, b"creator"))
+        .unwrap();
+    creator.require_auth();
+    creator
+}
+
+// Client for this DAO's profit token
+fn share_token(env: &Env) -> ProfitTokenContractClient<'_> {
+    let token_address: Address = env
+        .storage()
+        .instance()
+        .get(&Bytes::from_slice(env, b"token_contract_id"))
+        .unwrap();
+    ProfitTokenContractClient::new(env, &token_address)
+}
+
+// The part of the factory interface a DAO calls back into. Declared here
+// because the factory crate depends on this one, not the other way round.
+#[contractclient(name = "DAOFactoryClient")]
+pub trait DAOFactoryInterface {
+    fn update_dao_summary(env: Env, dao: Address, status: DaoStatus, total_raised: i128);
+}
+
+// Push the DAO's current status and raise to the factory's summary cache
+fn notify_factory(env: &Env) {
+    let inst = env.storage().instance();
+    let factory: Address = inst.get(&Bytes::from_slice(env, b"factory")).unwrap();
+    let total: i128 = inst.get(&Bytes::from_slice(env, b"total_raised")).unwrap();
+
+    DAOFactoryClient::new(env, &factory).update_dao_summary(
+        &env.current_contract_address(),
+        &DAOContract::get_status(env.clone()),
+        &total,
+    );
+}
+
 #[contract]
 pub struct DAOContract;
 
 #[contractimpl]
 impl DAOContract {
-    // Called by the factory immediately after instantiation
+    // Record the factory deploying this DAO; only it may initialize the DAO
+    pub fn __constructor(env: Env, factory: Address) {
+        env.storage()
+            .instance()
+            .set(&Bytes::from_slice(&env, b"factory"), &factory);
+    }
+
+    // Called once by the deploying factory immediately after instantiation
     pub fn initialize_dao(
         env: Env,
-        name: Symbol,
-        description: Symbol,
-        funding_goal: u64,
+        info: Content,
+        funding_goal: i128,
         creator: Address,
         token_contract_id: Address,
+        config: DaoConfig,
+        milestones: Vec<Milestone>,
     ) {
         let inst = env.storage().instance();
-        inst.set(&Bytes::from_slice(&env, b"name"), &name);
-        inst.set(&Bytes::from_slice(&env, b"description"), &description);
+        assert!(
+            !inst.has(&Bytes::from_slice(&env, b"creator")),
+            "DAO already initialized"
+        );
+        let factory: Address = inst.get(&Bytes::from_slice(&env, b"factory")).unwrap();
+        factory.require_auth();
+
+        validate_content(&info);
+        assert!(config.voting_period > 0, "Voting period must be positive");
+        assert!(
+            config.quorum_bps <= BPS_DENOMINATOR
+                && config.approval_threshold_bps <= BPS_DENOMINATOR,
+            "Thresholds must be at most 10000 bps"
+        );
+        assert!(
+            config.proposal_threshold >= 0,
+            "Proposal threshold must be non-negative"
+        );
+        assert!(
+            config.max_active_proposals > 0,
+            "Max active proposals must be positive"
+        );
+        assert!(
+            config.min_investment > 0 && config.min_investment <= config.max_investment,
+            "Invalid investment limits"
+        );
+        assert!(
+            config.hard_cap >= funding_goal,
+            "Hard cap must be at least the funding goal"
+        );
+
+        // Milestones, if any, must split the whole goal and fall due in order
+        if !milestones.is_empty() {
+            let mut sum = 0i128;
+            let mut last_due = 0u64;
+            for m in milestones.iter() {
+                assert!(m.amount > 0, "Milestone amount must be positive");
+                assert!(
+                    m.due_date > last_due,
+                    "Milestones must be in due-date order"
+                );
+                sum = add(&env, sum, m.amount);
+                last_due = m.due_date;
+            }
+            assert!(
+                sum == funding_goal,
+                "Milestones must add up to the funding goal"
+            );
+        }
+
+        inst.set(&Bytes::from_slice(&env, b"info"), &info);
+        inst.set(&Bytes::from_slice(&env, b"milestones"), &milestones);
+        inst.set(&Bytes::from_slice(&env, b"next_milestone"), &0u32);
+        inst.set(&Bytes::from_slice(&env, b"refunding"), &false);
+        inst.set(
+            &Bytes::from_slice(&env, b"investor_gate"),
+            &InvestorGate::Open,
+        );
         inst.set(&Bytes::from_slice(&env, b"funding_goal"), &funding_goal);
         inst.set(&Bytes::from_slice(&env, b"creator"), &creator);
-        inst.set(&Bytes::from_slice(&env, b"total_raised"), &0u64);
+        // The creator starts out as guardian with the power to veto proposals
+        inst.set(&Bytes::from_slice(&env, b"guardian"), &creator);
+        inst.set(
+            &Bytes::from_slice(&env, b"funding_deadline"),
+            &add_time(&env, env.ledger().timestamp(), config.funding_period),
+        );
+        inst.set(&Bytes::from_slice(&env, b"total_raised"), &0i128);
         inst.set(&Bytes::from_slice(&env, b"next_proposal_id"), &0u64);
         inst.set(
             &Bytes::from_slice(&env, b"investors"),
@@ -33,32 +673,26 @@ impl DAOContract {
         );
         inst.set(
             &Bytes::from_slice(&env, b"investments"),
-            &Map::<Address, u64>::new(&env),
-        );
-        inst.set(
-            &Bytes::from_slice(&env, b"proposal_details"),
-            &Map::<u64, Symbol>::new(&env),
-        );
-        inst.set(
-            &Bytes::from_slice(&env, b"proposal_votes"),
-            &Map::<u64, i128>::new(&env),
-        );
-        inst.set(
-            &Bytes::from_slice(&env, b"proposal_executed"),
-            &Map::<u64, bool>::new(&env),
+            &Map::<Address, i128>::new(&env),
         );
         inst.set(
             &Bytes::from_slice(&env, b"token_contract_id"),
             &token_contract_id,
         );
-        inst.set(
-            &Bytes::from_slice(&env, b"distribution_history"),
-            &Vec::<(u64, u64)>::new(&env),
+
+        // The DAO is the token admin, so it alone can mint shares
+        ProfitTokenContractClient::new(&env, &token_contract_id).initialize_ptc(
+            &env.current_contract_address(),
+            &config.token_name,
+            &config.token_symbol,
+            &config.compliance_enabled,
         );
+        inst.set(&Bytes::from_slice(&env, b"config"), &config);
     }
 
-    // Accept funds, record the investor, and mint profit‐share tokens
-    pub fn invest(env: Env, investor: Address, amount: u64) {
+    // Accept funds, record the investor, and mint profit‐share tokens. Only the
+    // part of `amount` that fits under the hard cap is taken; returns that amount.
+    pub fn invest(env: Env, investor: Address, amount: i128) -> i128 {
         // Authenticate the investor
         investor.require_auth();
 
@@ -66,16 +700,41 @@ impl DAOContract {
         assert!(amount > 0, "Investment amount must be positive");
 
         let inst = env.storage().instance();
-        // 1. Update total_raised
-        let mut total: u64 = inst.get(&Bytes::from_slice(&env, b"total_raised")).unwrap();
-        total += amount;
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+
+        // 0. Enforce the raise window and ticket size
+        let refunding: bool = inst.get(&Bytes::from_slice(&env, b"refunding")).unwrap();
+        assert!(!refunding, "DAO is refunding investors");
+        let deadline: u64 = inst
+            .get(&Bytes::from_slice(&env, b"funding_deadline"))
+            .unwrap();
+        assert!(
+            env.ledger().timestamp() <= deadline,
+            "Funding period has ended"
+        );
+        assert!(amount >= config.min_investment, "Investment below minimum");
+        assert!(
+            Self::is_investor_allowed(env.clone(), investor.clone()),
+            "Investor is not allowed"
+        );
+
+        // 1. Clip to the hard cap and update total_raised
+        let mut total: i128 = inst.get(&Bytes::from_slice(&env, b"total_raised")).unwrap();
+        assert!(total < config.hard_cap, "Hard cap reached");
+        let amount = amount.min(sub(&env, config.hard_cap, total));
+        total = add(&env, total, amount);
         inst.set(&Bytes::from_slice(&env, b"total_raised"), &total);
 
         // 2. Record individual investment
-        let mut invest_map: Map<Address, u64> =
+        let mut invest_map: Map<Address, i128> =
             inst.get(&Bytes::from_slice(&env, b"investments")).unwrap();
-        let prev: u64 = invest_map.get(investor.clone()).unwrap_or(0);
-        invest_map.set(investor.clone(), prev + amount);
+        let prev: i128 = invest_map.get(investor.clone()).unwrap_or(0);
+        let invested = add(&env, prev, amount);
+        assert!(
+            invested <= config.max_investment,
+            "Investment above per-investor maximum"
+        );
+        invest_map.set(investor.clone(), invested);
         inst.set(&Bytes::from_slice(&env, b"investments"), &invest_map);
 
         // 3. Track unique investors
@@ -85,143 +744,684 @@ impl DAOContract {
             inst.set(&Bytes::from_slice(&env, b"investors"), &invs);
         }
 
-        // 4. Mint profit‐share tokens
+        // 4. Collect the payment and mint profit‐share tokens
+        TokenClient::new(&env, &config.accepted_asset).transfer(
+            &investor,
+            &env.current_contract_address(),
+            &amount,
+        );
+
         let token_address: Address = inst
             .get(&Bytes::from_slice(&env, b"token_contract_id"))
             .unwrap();
         let token = ProfitTokenContractClient::new(&env, &token_address);
-        token.mint(&investor, &(amount as i128));
+        token.mint_locked(
+            &investor,
+            &amount,
+            &add_time(&env, env.ledger().timestamp(), config.lockup_period),
+        );
+
+        // 5. Keep the factory's listing in sync
+        notify_factory(&env);
+
+        amount
     }
 
-    // Create a new proposal, return its ID
-    pub fn create_proposal(env: Env, details: Symbol) -> u64 {
+    // Called by the profit distribution contract after it has sent `amount`
+    // of the accepted asset to the treasury on `investor`'s behalf: mint
+    // shares for it at the `invest` price. Profits arrive after the raise, so
+    // the raise window, ticket limits and hard cap don't apply, and the amount
+    // is counted in `total_reinvested` rather than `total_raised`.
+    pub fn reinvest_profit(env: Env, investor: Address, amount: i128) {
         let inst = env.storage().instance();
-        let mut pid: u64 = inst
-            .get(&Bytes::from_slice(&env, b"next_proposal_id"))
-            .unwrap();
-        // Store proposal text
-        let mut pm: Map<u64, Symbol> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_details"))
-            .unwrap();
-        pm.set(pid, details);
-        inst.set(&Bytes::from_slice(&env, b"proposal_details"), &pm);
-        // Initialize vote tally
-        let mut vm: Map<u64, i128> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_votes"))
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+        config.profit_distributor.require_auth();
+        assert!(amount > 0, "Reinvested amount must be positive");
+        // New shares would dilute the fixed refund pool
+        let refunding: bool = inst.get(&Bytes::from_slice(&env, b"refunding")).unwrap();
+        assert!(!refunding, "DAO is refunding investors");
+        assert!(
+            Self::is_investor_allowed(env.clone(), investor.clone()),
+            "Investor is not allowed"
+        );
+
+        let total = Self::get_total_reinvested(env.clone());
+        inst.set(
+            &Bytes::from_slice(&env, b"total_reinvested"),
+            &add(&env, total, amount),
+        );
+
+        let mut invs: Vec<Address> = inst.get(&Bytes::from_slice(&env, b"investors")).unwrap();
+        if !invs.contains(&investor) {
+            invs.push_back(investor.clone());
+            inst.set(&Bytes::from_slice(&env, b"investors"), &invs);
+        }
+
+        share_token(&env).mint(&investor, &amount);
+    }
+
+    // Create a new proposal on behalf of `proposer`, return its ID
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        content: Content,
+        action: ProposalAction,
+    ) -> u64 {
+        // Authenticate the proposer
+        proposer.require_auth();
+        validate_content(&content);
+        validate_action(&env, &action);
+
+        let inst = env.storage().instance();
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+
+        // Proposer must hold enough profit tokens
+        let token_address: Address = inst
+            .get(&Bytes::from_slice(&env, b"token_contract_id"))
             .unwrap();
-        vm.set(pid, 0i128);
-        inst.set(&Bytes::from_slice(&env, b"proposal_votes"), &vm);
-        // Mark as un‐executed
-        let mut em: Map<u64, bool> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_executed"))
+        let balance = ProfitTokenContractClient::new(&env, &token_address).balance(&proposer);
+        assert!(
+            balance >= config.proposal_threshold,
+            "Proposer below proposal threshold"
+        );
+
+        // ... and must not have too many proposals open already
+        assert!(
+            count_active_proposals(&env, &proposer) < config.max_active_proposals,
+            "Too many active proposals"
+        );
+
+        let pid: u64 = inst
+            .get(&Bytes::from_slice(&env, b"next_proposal_id"))
             .unwrap();
-        em.set(pid, false);
-        inst.set(&Bytes::from_slice(&env, b"proposal_executed"), &em);
+
+        // Store the proposal and schedule its voting window
+        let start = add_time(&env, env.ledger().timestamp(), config.voting_delay);
+        save_proposal(
+            &env,
+            &Proposal {
+                id: pid,
+                proposer: proposer.clone(),
+                content,
+                action,
+                votes_for: 0,
+                votes_against: 0,
+                votes_abstain: 0,
+                start,
+                deadline: add_time(&env, start, config.voting_period),
+                eta: 0,
+                status: ProposalStatus::Pending,
+            },
+        );
+
+        // Track it against the proposer's cap
+        let persistent = env.storage().persistent();
+        let key = DataKey::ActiveProposals(proposer);
+        let mut ids: Vec<u64> = persistent.get(&key).unwrap_or(Vec::new(&env));
+        ids.push_back(pid);
+        persistent.set(&key, &ids);
+
         // Bump next ID
-        pid += 1;
-        inst.set(&Bytes::from_slice(&env, b"next_proposal_id"), &pid);
-        pid - 1
+        inst.set(&Bytes::from_slice(&env, b"next_proposal_id"), &(pid + 1));
+        pid
     }
 
-    // Vote yes/no weighted by token balance
-    pub fn vote(env: Env, voter: Address, proposal_id: u64, support: bool) {
+    // Vote for, against or abstain, weighted by delegated voting power, with an
+    // optional short reason
+    pub fn vote(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        choice: VoteChoice,
+        reason: Option<String>,
+    ) {
         // Authenticate the voter
         voter.require_auth();
+        let reason = reason.unwrap_or(String::from_str(&env, ""));
+        assert!(reason.len() <= MAX_REASON_LEN, "Reason too long");
 
-        // Check proposal exists
+        // Check proposal exists and is open for voting
         let inst = env.storage().instance();
-        let pm: Map<u64, Symbol> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_details"))
-            .unwrap();
-        assert!(pm.contains_key(proposal_id), "Proposal does not exist");
+        let persistent = env.storage().persistent();
+        let mut proposal = load_proposal(&env, proposal_id);
+        assert!(
+            proposal_state(&env, &proposal) == ProposalStatus::Active,
+            "Proposal is not active"
+        );
 
-        // Check proposal not executed
-        let em: Map<u64, bool> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_executed"))
-            .unwrap();
-        assert!(!em.get(proposal_id).unwrap(), "Proposal already executed");
+        // One vote per address
+        let ballot_key = DataKey::Ballot(proposal_id, voter.clone());
+        assert!(!persistent.has(&ballot_key), "Already voted");
 
         // Fetch weight
         let token_address: Address = inst
             .get(&Bytes::from_slice(&env, b"token_contract_id"))
             .unwrap();
 
+        // Delegated voting power as of the start of voting
         let token = ProfitTokenContractClient::new(&env, &token_address);
-        let w: i128 = token.balance(&voter);
+        let w: i128 = token.get_past_votes(&voter, &proposal.start);
 
         // Ensure voter has tokens
         assert!(w > 0, "Voter has no voting power");
 
         // Tally
-        let mut vm: Map<u64, i128> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_votes"))
-            .unwrap();
-        let mut tally: i128 = vm.get(proposal_id).unwrap();
-        tally += if support { w } else { -w };
-        vm.set(proposal_id, tally);
-        inst.set(&Bytes::from_slice(&env, b"proposal_votes"), &vm);
+        match choice {
+            VoteChoice::For => proposal.votes_for = add(&env, proposal.votes_for, w),
+            VoteChoice::Against => proposal.votes_against = add(&env, proposal.votes_against, w),
+            VoteChoice::Abstain => proposal.votes_abstain = add(&env, proposal.votes_abstain, w),
+        }
+        save_proposal(&env, &proposal);
+
+        persistent.set(
+            &ballot_key,
+            &Ballot {
+                choice,
+                weight: w,
+                reason: reason.clone(),
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("vote"), proposal_id, voter),
+            (choice, w, reason),
+        );
+    }
+
+    // Queue a proposal whose vote succeeded, starting the timelock
+    pub fn queue(env: Env, proposal_id: u64) -> u64 {
+        let inst = env.storage().instance();
+
+        let mut proposal = load_proposal(&env, proposal_id);
+        assert!(
+            proposal_state(&env, &proposal) == ProposalStatus::Succeeded,
+            "Proposal has not succeeded"
+        );
+
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+
+        // A payout must be covered by funds not already promised elsewhere,
+        // including milestone tranches still owed to the creator
+        let amount = match &proposal.action {
+            ProposalAction::Payout(_, amount) => *amount,
+            ProposalAction::ReleaseMilestone(index) => {
+                let milestones: Vec<Milestone> =
+                    inst.get(&Bytes::from_slice(&env, b"milestones")).unwrap();
+                milestones.get_unchecked(*index).amount
+            }
+            _ => 0,
+        };
+        if amount > 0 {
+            let balance = TokenClient::new(&env, &config.accepted_asset)
+                .balance(&env.current_contract_address());
+            let mut available = sub(&env, balance, committed_funds(&env));
+            if let ProposalAction::Payout(..) = proposal.action {
+                available = sub(&env, available, unqueued_tranches(&env));
+            }
+            assert!(amount <= available, "Insufficient uncommitted treasury");
+        }
+
+        proposal.eta = add_time(&env, env.ledger().timestamp(), config.timelock_delay);
+        proposal.status = ProposalStatus::Queued;
+        save_proposal(&env, &proposal);
+
+        // Track it so ragequit knows a timelock window is open
+        let mut queued: Vec<u64> = inst
+            .get(&Bytes::from_slice(&env, b"queued_proposals"))
+            .unwrap_or(Vec::new(&env));
+        queued.push_back(proposal_id);
+        inst.set(&Bytes::from_slice(&env, b"queued_proposals"), &queued);
+
+        proposal.eta
     }
 
-    // Execute if tally > 0 and not yet executed
+    // Execute a queued proposal once its timelock has passed, before it expires
     pub fn execute_proposal(env: Env, proposal_id: u64) {
         let inst = env.storage().instance();
 
-        // Check proposal exists
-        let pm: Map<u64, Symbol> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_details"))
+        let mut proposal = load_proposal(&env, proposal_id);
+        assert!(
+            proposal_state(&env, &proposal) == ProposalStatus::Queued,
+            "Proposal is not queued"
+        );
+        assert!(
+            env.ledger().timestamp() >= proposal.eta,
+            "Timelock has not expired"
+        );
+        // A refund freezes the treasury for claim_refund
+        let refunding: bool = inst.get(&Bytes::from_slice(&env, b"refunding")).unwrap();
+        assert!(!refunding, "DAO is refunding investors");
+
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+
+        let asset = TokenClient::new(&env, &config.accepted_asset);
+        match &proposal.action {
+            ProposalAction::None => {}
+            ProposalAction::Payout(recipient, amount) => {
+                asset.transfer(&env.current_contract_address(), recipient, amount);
+            }
+            ProposalAction::ReleaseMilestone(index) => {
+                // Tranches are released strictly in order
+                let next: u32 = inst
+                    .get(&Bytes::from_slice(&env, b"next_milestone"))
+                    .unwrap();
+                assert!(*index == next, "Milestone already released");
+                let milestones: Vec<Milestone> =
+                    inst.get(&Bytes::from_slice(&env, b"milestones")).unwrap();
+                let creator: Address = inst.get(&Bytes::from_slice(&env, b"creator")).unwrap();
+                asset.transfer(
+                    &env.current_contract_address(),
+                    &creator,
+                    &milestones.get_unchecked(*index).amount,
+                );
+                inst.set(&Bytes::from_slice(&env, b"next_milestone"), &(next + 1));
+            }
+            ProposalAction::Refund => {
+                // Snapshot the pool so every investor is refunded at the same rate
+                let token_address: Address = inst
+                    .get(&Bytes::from_slice(&env, b"token_contract_id"))
+                    .unwrap();
+                let supply = ProfitTokenContractClient::new(&env, &token_address).total_supply();
+                let pool = asset.balance(&env.current_contract_address());
+                inst.set(&Bytes::from_slice(&env, b"refunding"), &true);
+                inst.set(&Bytes::from_slice(&env, b"refund_pool"), &(pool, supply));
+            }
+            ProposalAction::GrantVesting(grant) => {
+                let mut schedules: Map<Address, Vec<VestingSchedule>> = inst
+                    .get(&Bytes::from_slice(&env, b"vesting"))
+                    .unwrap_or(Map::new(&env));
+                let mut list = schedules
+                    .get(grant.beneficiary.clone())
+                    .unwrap_or(Vec::new(&env));
+                list.push_back(VestingSchedule {
+                    grant: grant.clone(),
+                    start: env.ledger().timestamp(),
+                    claimed: 0,
+                });
+                schedules.set(grant.beneficiary.clone(), list);
+                inst.set(&Bytes::from_slice(&env, b"vesting"), &schedules);
+            }
+            ProposalAction::MintShares(recipients) => share_token(&env).mint_batch(recipients),
+            ProposalAction::SetShareMaxSupply(max_supply) => {
+                share_token(&env).set_max_supply(&Some(*max_supply))
+            }
+            ProposalAction::RemoveShareMaxSupply => share_token(&env).set_max_supply(&None),
+            ProposalAction::SetShareAdmin(admin) => share_token(&env).set_admin(admin),
+        }
+
+        // Mark proposal as executed
+        proposal.status = ProposalStatus::Executed;
+        save_proposal(&env, &proposal);
+
+        if proposal.action == ProposalAction::Refund {
+            notify_factory(&env);
+        }
+    }
+
+    // Once a refund has been voted through, burn all of the investor's profit
+    // tokens and return their pro-rata share of the refund pool
+    pub fn claim_refund(env: Env, investor: Address) -> i128 {
+        investor.require_auth();
+
+        let inst = env.storage().instance();
+        let refunding: bool = inst.get(&Bytes::from_slice(&env, b"refunding")).unwrap();
+        assert!(refunding, "No refund in progress");
+
+        let token_address: Address = inst
+            .get(&Bytes::from_slice(&env, b"token_contract_id"))
             .unwrap();
-        assert!(pm.contains_key(proposal_id), "Proposal does not exist");
+        let token = ProfitTokenContractClient::new(&env, &token_address);
+        let shares = token.balance(&investor);
+        assert!(shares > 0, "Nothing to refund");
+
+        let (pool, supply): (i128, i128) =
+            inst.get(&Bytes::from_slice(&env, b"refund_pool")).unwrap();
+        let refund = mul_div(&env, pool, shares, supply);
+
+        token.burn(&investor, &shares);
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+        TokenClient::new(&env, &config.accepted_asset).transfer(
+            &env.current_contract_address(),
+            &investor,
+            &refund,
+        );
+        refund
+    }
 
-        let mut em: Map<u64, bool> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_executed"))
+    // Mint every vested but unclaimed profit token to `beneficiary`; returns the
+    // amount. Closed while a refund is in progress, as the refund pool was
+    // priced against the supply at the time of the vote.
+    pub fn claim_vested(env: Env, beneficiary: Address) -> i128 {
+        beneficiary.require_auth();
+
+        let inst = env.storage().instance();
+        let refunding: bool = inst.get(&Bytes::from_slice(&env, b"refunding")).unwrap();
+        assert!(!refunding, "DAO is refunding investors");
+
+        let mut schedules: Map<Address, Vec<VestingSchedule>> = inst
+            .get(&Bytes::from_slice(&env, b"vesting"))
+            .unwrap_or(Map::new(&env));
+        let list = schedules
+            .get(beneficiary.clone())
+            .expect("No vesting schedule");
+
+        let now = env.ledger().timestamp();
+        let mut updated: Vec<VestingSchedule> = Vec::new(&env);
+        let mut claimable = 0i128;
+        for mut schedule in list.iter() {
+            let vested = vested_amount(&env, &schedule, now);
+            claimable = add(&env, claimable, sub(&env, vested, schedule.claimed));
+            schedule.claimed = vested;
+            updated.push_back(schedule);
+        }
+        assert!(claimable > 0, "Nothing vested to claim");
+
+        schedules.set(beneficiary.clone(), updated);
+        inst.set(&Bytes::from_slice(&env, b"vesting"), &schedules);
+
+        let token_address: Address = inst
+            .get(&Bytes::from_slice(&env, b"token_contract_id"))
             .unwrap();
-        assert!(!em.get(proposal_id).unwrap(), "Already executed");
-        let vm: Map<u64, i128> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_votes"))
+        ProfitTokenContractClient::new(&env, &token_address).mint(&beneficiary, &claimable);
+        claimable
+    }
+
+    // Get the vesting schedules granted to `beneficiary`
+    pub fn get_vesting_schedules(env: Env, beneficiary: Address) -> Vec<VestingSchedule> {
+        let schedules: Map<Address, Vec<VestingSchedule>> = env
+            .storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"vesting"))
+            .unwrap_or(Map::new(&env));
+        schedules.get(beneficiary).unwrap_or(Vec::new(&env))
+    }
+
+    // Get how many profit tokens `beneficiary` could claim right now
+    pub fn get_claimable_vested(env: Env, beneficiary: Address) -> i128 {
+        let now = env.ledger().timestamp();
+        let mut claimable = 0i128;
+        for schedule in Self::get_vesting_schedules(env.clone(), beneficiary).iter() {
+            let vested = vested_amount(&env, &schedule, now);
+            claimable = add(&env, claimable, sub(&env, vested, schedule.claimed));
+        }
+        claimable
+    }
+
+    // Get the milestones the creator committed to
+    pub fn get_milestones(env: Env) -> Vec<Milestone> {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"milestones"))
+            .unwrap()
+    }
+
+    // Get the index of the next milestone to be released
+    pub fn get_next_milestone(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"next_milestone"))
+            .unwrap()
+    }
+
+    // Burn `shares` profit tokens and withdraw their pro-rata share of the treasury
+    // not already committed to queued proposals. Only open while a proposal sits
+    // in its timelock, and only to investors who did not vote for it.
+    pub fn ragequit(env: Env, investor: Address, shares: i128) -> i128 {
+        investor.require_auth();
+        assert!(shares > 0, "Shares must be positive");
+
+        let inst = env.storage().instance();
+        let refunding: bool = inst.get(&Bytes::from_slice(&env, b"refunding")).unwrap();
+        assert!(!refunding, "DAO is refunding investors, use claim_refund");
+
+        let now = env.ledger().timestamp();
+
+        let mut window_open = false;
+        for proposal in queued_proposals(&env).iter() {
+            let voted_for = Self::get_ballot(env.clone(), proposal.id, investor.clone())
+                .map(|b| b.choice == VoteChoice::For)
+                .unwrap_or(false);
+            if now < proposal.eta && !voted_for {
+                window_open = true;
+            }
+        }
+        assert!(window_open, "No timelock window open to this investor");
+
+        // Price the exit against the supply before burning
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+        let asset = TokenClient::new(&env, &config.accepted_asset);
+        let token_address: Address = inst
+            .get(&Bytes::from_slice(&env, b"token_contract_id"))
             .unwrap();
-        let tally: i128 = vm.get(proposal_id).unwrap();
-        assert!(tally > 0, "Not approved");
+        let token = ProfitTokenContractClient::new(&env, &token_address);
 
-        // Simplified payout logic: send entire pot to creator
-        let creator: Address = inst.get(&Bytes::from_slice(&env, b"creator")).unwrap();
-        let pot: u64 = inst.get(&Bytes::from_slice(&env, b"total_raised")).unwrap();
+        let supply = token.total_supply();
+        let uncommitted = sub(
+            &env,
+            asset.balance(&env.current_contract_address()),
+            committed_funds(&env),
+        );
+        let payout = if uncommitted > 0 {
+            mul_div(&env, uncommitted, shares, supply)
+        } else {
+            0
+        };
 
-        // Assume the payment contract already exists
-        let payment_address = Address::from_str(&env, "Payment");
+        token.burn(&investor, &shares);
+        if payout > 0 {
+            asset.transfer(&env.current_contract_address(), &investor, &payout);
+        }
 
-        // Properly convert arguments to Val
-        let args = vec![&env, creator.into_val(&env), pot.into_val(&env)];
+        // Shares were minted 1:1 with investment, so the burned ones no longer
+        // count toward the investor's ticket or the raise
+        let mut invest_map: Map<Address, i128> =
+            inst.get(&Bytes::from_slice(&env, b"investments")).unwrap();
+        let invested = invest_map.get(investor.clone()).unwrap_or(0);
+        let withdrawn = shares.min(invested);
+        if withdrawn > 0 {
+            if withdrawn == invested {
+                invest_map.remove(investor.clone());
+            } else {
+                invest_map.set(investor.clone(), sub(&env, invested, withdrawn));
+            }
+            inst.set(&Bytes::from_slice(&env, b"investments"), &invest_map);
 
-        // Invoke the transfer function and specify the return type as ()
-        env.invoke_contract::<()>(&payment_address, &symbol_short!("transfer"), args);
+            let total: i128 = inst.get(&Bytes::from_slice(&env, b"total_raised")).unwrap();
+            inst.set(
+                &Bytes::from_slice(&env, b"total_raised"),
+                &sub(&env, total, withdrawn),
+            );
+            notify_factory(&env);
+        }
 
-        // Mark proposal as executed
-        em.set(proposal_id, true);
-        inst.set(&Bytes::from_slice(&env, b"proposal_executed"), &em);
+        env.events()
+            .publish((symbol_short!("ragequit"), investor), (shares, payout));
+        payout
+    }
+
+    // Withdraw a proposal. The proposer may cancel until voting ends; the
+    // guardian may veto a proposal that has not been executed yet, except a
+    // refund (the guardian is usually the creator it is aimed at) and a payout
+    // already queued, since ragequit exits were priced with those funds set aside.
+    pub fn cancel_proposal(env: Env, caller: Address, proposal_id: u64) {
+        caller.require_auth();
+
+        let mut proposal = load_proposal(&env, proposal_id);
+        let state = proposal_state(&env, &proposal);
+        let guardian: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"guardian"));
+
+        if guardian == Some(caller.clone()) {
+            assert!(
+                state != ProposalStatus::Executed
+                    && state != ProposalStatus::Cancelled
+                    && state != ProposalStatus::Expired,
+                "Proposal can no longer be vetoed"
+            );
+            assert!(
+                proposal.action != ProposalAction::Refund,
+                "Refund proposals cannot be vetoed"
+            );
+            let payout = matches!(
+                proposal.action,
+                ProposalAction::Payout(..) | ProposalAction::ReleaseMilestone(_)
+            );
+            assert!(
+                !(payout && state == ProposalStatus::Queued),
+                "Queued payouts cannot be vetoed"
+            );
+        } else {
+            assert!(caller == proposal.proposer, "Not proposer or guardian");
+            assert!(
+                state == ProposalStatus::Pending || state == ProposalStatus::Active,
+                "Voting has ended"
+            );
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        save_proposal(&env, &proposal);
     }
 
-    // Record a profit distribution event
-    pub fn record_distribution(env: Env, timestamp: u64, amount: u64) {
-        // Ensure only the profit distribution contract can call this
-        // This logic would need to be expanded based on your authorization model
+    // Choose who may invest; also gates profit-token transfers to new holders
+    pub fn set_investor_gate(env: Env, gate: InvestorGate) {
+        require_creator(&env);
 
         let inst = env.storage().instance();
-        let mut history: Vec<(u64, u64)> = inst
-            .get(&Bytes::from_slice(&env, b"distribution_history"))
-            .unwrap();
+        inst.set(&Bytes::from_slice(&env, b"investor_gate"), &gate);
 
-        history.push_back((timestamp, amount));
-        inst.set(&Bytes::from_slice(&env, b"distribution_history"), &history);
+        let token_address: Address = inst
+            .get(&Bytes::from_slice(&env, b"token_contract_id"))
+            .unwrap();
+        let token_gate = match gate {
+            InvestorGate::Open => None,
+            _ => Some(env.current_contract_address()),
+        };
+        ProfitTokenContractClient::new(&env, &token_address).set_transfer_gate(&token_gate);
     }
 
-    // Get distribution history
-    pub fn get_distribution_history(env: Env) -> Vec<(u64, u64)> {
+    pub fn get_investor_gate(env: Env) -> InvestorGate {
         env.storage()
             .instance()
-            .get(&Bytes::from_slice(&env, b"distribution_history"))
+            .get(&Bytes::from_slice(&env, b"investor_gate"))
             .unwrap()
     }
 
+    // Add an address to the creator-managed allowlist
+    pub fn allow_investor(env: Env, investor: Address) {
+        require_creator(&env);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Allowed(investor), &true);
+    }
+
+    // Remove an address from the creator-managed allowlist
+    pub fn disallow_investor(env: Env, investor: Address) {
+        require_creator(&env);
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Allowed(investor));
+    }
+
+    // Whether `account` passes the current investor gate
+    pub fn is_investor_allowed(env: Env, account: Address) -> bool {
+        match Self::get_investor_gate(env.clone()) {
+            InvestorGate::Open => true,
+            InvestorGate::Allowlist => env.storage().persistent().has(&DataKey::Allowed(account)),
+            InvestorGate::Attestation(provider) => {
+                AttestationClient::new(&env, &provider).is_verified(&account)
+            }
+        }
+    }
+
+    // Let the creator switch profit-token transfers on or off for all holders
+    pub fn set_share_transfers_enabled(env: Env, enabled: bool) {
+        require_creator(&env);
+        share_token(&env).set_transfers_enabled(&enabled);
+    }
+
+    // Freeze a holder's profit tokens on the creator's instruction
+    pub fn freeze_shares(env: Env, account: Address) {
+        require_creator(&env);
+        share_token(&env).freeze(&account);
+    }
+
+    pub fn unfreeze_shares(env: Env, account: Address) {
+        require_creator(&env);
+        share_token(&env).unfreeze(&account);
+    }
+
+    // Claw back (burn) a holder's profit tokens, e.g. under a court order
+    pub fn clawback_shares(env: Env, from: Address, amount: i128) {
+        require_creator(&env);
+        share_token(&env).clawback(&from, &amount);
+    }
+
+    // Hand the veto role to someone else, or renounce it with None
+    pub fn set_guardian(env: Env, new_guardian: Option<Address>) {
+        let inst = env.storage().instance();
+        let guardian: Address = inst
+            .get(&Bytes::from_slice(&env, b"guardian"))
+            .expect("No guardian");
+        guardian.require_auth();
+
+        match new_guardian {
+            Some(addr) => inst.set(&Bytes::from_slice(&env, b"guardian"), &addr),
+            None => inst.remove(&Bytes::from_slice(&env, b"guardian")),
+        }
+    }
+
+    // Get the address holding the veto role, if any
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"guardian"))
+    }
+
+    // Record a completed profit distribution; only the profit distributor may
+    pub fn record_distribution(env: Env, timestamp: u64, amount: i128) {
+        let inst = env.storage().instance();
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+        config.profit_distributor.require_auth();
+
+        let count = Self::get_distribution_count(env.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Distribution(count), &(timestamp, amount));
+        inst.set(
+            &Bytes::from_slice(&env, b"distribution_count"),
+            &(count + 1),
+        );
+    }
+
+    // Number of profit distributions recorded
+    pub fn get_distribution_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"distribution_count"))
+            .unwrap_or(0)
+    }
+
+    // Get up to `limit` recorded distributions as (timestamp, amount), oldest
+    // first, starting at position `start`
+    pub fn get_distribution_history(env: Env, start: u32, limit: u32) -> Vec<(u64, i128)> {
+        let total = Self::get_distribution_count(env.clone());
+
+        let mut result: Vec<(u64, i128)> = Vec::new(&env);
+        for i in start..start.saturating_add(limit).min(total) {
+            result.push_back(
+                env.storage()
+                    .persistent()
+                    .get(&DataKey::Distribution(i))
+                    .unwrap(),
+            );
+        }
+        result
+    }
+
     // Get all proposals
     pub fn get_proposals_count(env: Env) -> u64 {
         env.storage()
@@ -230,40 +1430,86 @@ impl DAOContract {
             .unwrap()
     }
 
-    // Get proposal details
-    pub fn get_proposal_details(env: Env, proposal_id: u64) -> Symbol {
-        let inst = env.storage().instance();
-        let pm: Map<u64, Symbol> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_details"))
+    // Get a full proposal
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Proposal {
+        let mut proposal = load_proposal(&env, proposal_id);
+        proposal.status = proposal_state(&env, &proposal);
+        proposal
+    }
+
+    // Get up to `limit` proposals starting at ID `start`
+    pub fn get_proposals(env: Env, start: u64, limit: u64) -> Vec<Proposal> {
+        let total: u64 = env
+            .storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"next_proposal_id"))
             .unwrap();
 
-        assert!(pm.contains_key(proposal_id), "Proposal does not exist");
-        pm.get(proposal_id).unwrap()
+        let mut result: Vec<Proposal> = Vec::new(&env);
+        for id in start..start.saturating_add(limit).min(total) {
+            let stored: Option<Proposal> = env.storage().persistent().get(&DataKey::Proposal(id));
+            if let Some(mut proposal) = stored {
+                proposal.status = proposal_state(&env, &proposal);
+                result.push_back(proposal);
+            }
+        }
+        result
     }
 
-    // Get proposal votes
-    pub fn get_proposal_votes(env: Env, proposal_id: u64) -> i128 {
-        let inst = env.storage().instance();
-        let vm: Map<u64, i128> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_votes"))
-            .unwrap();
+    // Get the address that created a proposal
+    pub fn get_proposer(env: Env, proposal_id: u64) -> Address {
+        load_proposal(&env, proposal_id).proposer
+    }
 
-        assert!(vm.contains_key(proposal_id), "Proposal does not exist");
-        vm.get(proposal_id).unwrap()
+    // Get proposal details
+    pub fn get_proposal_details(env: Env, proposal_id: u64) -> Content {
+        load_proposal(&env, proposal_id).content
+    }
+
+    // Get proposal votes (net of votes against)
+    pub fn get_proposal_votes(env: Env, proposal_id: u64) -> i128 {
+        let proposal = load_proposal(&env, proposal_id);
+        sub(&env, proposal.votes_for, proposal.votes_against)
     }
 
     // Get proposal execution status
     pub fn get_proposal_executed(env: Env, proposal_id: u64) -> bool {
-        let inst = env.storage().instance();
-        let em: Map<u64, bool> = inst
-            .get(&Bytes::from_slice(&env, b"proposal_executed"))
-            .unwrap();
+        load_proposal(&env, proposal_id).status == ProposalStatus::Executed
+    }
+
+    // Get the current lifecycle state of a proposal
+    pub fn get_proposal_state(env: Env, proposal_id: u64) -> ProposalStatus {
+        proposal_state(&env, &load_proposal(&env, proposal_id))
+    }
+
+    // Get the votes for, against and abstaining on a proposal
+    pub fn get_proposal_tally(env: Env, proposal_id: u64) -> (i128, i128, i128) {
+        let proposal = load_proposal(&env, proposal_id);
+        (
+            proposal.votes_for,
+            proposal.votes_against,
+            proposal.votes_abstain,
+        )
+    }
+
+    // Get the ballot `voter` cast on a proposal, if any
+    pub fn get_ballot(env: Env, proposal_id: u64, voter: Address) -> Option<Ballot> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Ballot(proposal_id, voter))
+    }
+
+    // Get the earliest execution time of a queued proposal
+    pub fn get_proposal_eta(env: Env, proposal_id: u64) -> u64 {
+        load_proposal(&env, proposal_id).eta
+    }
 
-        assert!(em.contains_key(proposal_id), "Proposal does not exist");
-        em.get(proposal_id).unwrap()
+    // Get the time at which voting on a proposal closes
+    pub fn get_proposal_deadline(env: Env, proposal_id: u64) -> u64 {
+        load_proposal(&env, proposal_id).deadline
     }
 
-    // Get voting power for an address
+    // Get voting power for an address, including balances delegated to it
     pub fn get_voting_power(env: Env, voter: Address) -> i128 {
         let inst = env.storage().instance();
         let token_address: Address = inst
@@ -271,26 +1517,68 @@ impl DAOContract {
             .unwrap();
 
         let token = ProfitTokenContractClient::new(&env, &token_address);
-        token.balance(&voter)
+        token.get_votes(&voter)
     }
 
     // Get total raised funds
-    pub fn get_total_raised(env: Env) -> u64 {
+    pub fn get_total_raised(env: Env) -> i128 {
         env.storage()
             .instance()
             .get(&Bytes::from_slice(&env, b"total_raised"))
             .unwrap()
     }
 
+    // Get the profits reinvested into the treasury through `reinvest_profit`
+    pub fn get_total_reinvested(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"total_reinvested"))
+            .unwrap_or(0)
+    }
+
     // Check if funding goal has been reached
     pub fn is_funding_goal_reached(env: Env) -> bool {
         let inst = env.storage().instance();
-        let total: u64 = inst.get(&Bytes::from_slice(&env, b"total_raised")).unwrap();
-        let goal: u64 = inst.get(&Bytes::from_slice(&env, b"funding_goal")).unwrap();
+        let total: i128 = inst.get(&Bytes::from_slice(&env, b"total_raised")).unwrap();
+        let goal: i128 = inst.get(&Bytes::from_slice(&env, b"funding_goal")).unwrap();
 
         total >= goal
     }
 
+    // Get the raise limits and progress
+    pub fn get_raise_terms(env: Env) -> RaiseTerms {
+        let inst = env.storage().instance();
+        let config: DaoConfig = inst.get(&Bytes::from_slice(&env, b"config")).unwrap();
+
+        RaiseTerms {
+            accepted_asset: config.accepted_asset,
+            funding_goal: inst.get(&Bytes::from_slice(&env, b"funding_goal")).unwrap(),
+            hard_cap: config.hard_cap,
+            min_investment: config.min_investment,
+            max_investment: config.max_investment,
+            funding_deadline: inst
+                .get(&Bytes::from_slice(&env, b"funding_deadline"))
+                .unwrap(),
+            total_raised: inst.get(&Bytes::from_slice(&env, b"total_raised")).unwrap(),
+        }
+    }
+
+    // Current funding stage of the DAO
+    pub fn get_status(env: Env) -> DaoStatus {
+        let refunding: bool = env
+            .storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"refunding"))
+            .unwrap();
+        if refunding {
+            DaoStatus::Refunding
+        } else if Self::is_funding_goal_reached(env) {
+            DaoStatus::Funded
+        } else {
+            DaoStatus::Fundraising
+        }
+    }
+
     // Expose helpers for the distribution contract
     pub fn get_investors(env: Env) -> Vec<Address> {
         env.storage()
@@ -306,7 +1594,7 @@ impl DAOContract {
             .unwrap()
     }
 
-    pub fn get_investments(env: Env) -> Map<Address, u64> {
+    pub fn get_investments(env: Env) -> Map<Address, i128> {
         env.storage()
             .instance()
             .get(&Bytes::from_slice(&env, b"investments"))
@@ -314,24 +1602,46 @@ impl DAOContract {
     }
 
     // Expose getters for the factory contract
-    pub fn get_name(env: Env) -> Symbol {
+    pub fn get_info(env: Env) -> Content {
         env.storage()
             .instance()
-            .get(&Bytes::from_slice(&env, b"name"))
+            .get(&Bytes::from_slice(&env, b"info"))
             .unwrap()
     }
 
-    pub fn get_description(env: Env) -> Symbol {
+    pub fn get_name(env: Env) -> String {
+        Self::get_info(env).title
+    }
+
+    pub fn get_description(env: Env) -> ContentBody {
+        Self::get_info(env).body
+    }
+
+    pub fn get_funding_goal(env: Env) -> i128 {
         env.storage()
             .instance()
-            .get(&Bytes::from_slice(&env, b"description"))
+            .get(&Bytes::from_slice(&env, b"funding_goal"))
             .unwrap()
     }
 
-    pub fn get_funding_goal(env: Env) -> u64 {
+    pub fn get_config(env: Env) -> DaoConfig {
         env.storage()
             .instance()
-            .get(&Bytes::from_slice(&env, b"funding_goal"))
+            .get(&Bytes::from_slice(&env, b"config"))
+            .unwrap()
+    }
+
+    pub fn get_funding_deadline(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"funding_deadline"))
+            .unwrap()
+    }
+
+    pub fn get_factory(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&Bytes::from_slice(&env, b"factory"))
             .unwrap()
     }
 
@@ -342,3 +1652,9 @@ impl DAOContract {
             .unwrap()
     }
 }
+
+#[cfg(test)]
+mod test;
+
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
@@ -0,0 +1,730 @@
+#![cfg(test)]
+use super::*;
+use crate::testutils::{
+    config, info, initialize, initialize_with_milestones, invest, setup, Setup,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+};
+
+// Tranches of 400 due at 2000 and 600 due at 3000
+fn milestones(env: &Env) -> Vec<Milestone> {
+    vec![
+        env,
+        Milestone {
+            description: String::from_str(env, "Fit-out"),
+            amount: 400,
+            due_date: 2_000,
+        },
+        Milestone {
+            description: String::from_str(env, "Opening"),
+            amount: 600,
+            due_date: 3_000,
+        },
+    ]
+}
+
+fn propose(s: &Setup, proposer: &Address, action: ProposalAction) -> u64 {
+    s.dao.create_proposal(proposer, &info(&s.env), &action)
+}
+
+fn advance(s: &Setup, seconds: u64) {
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + seconds);
+}
+
+// Balance of the accepted asset
+fn balance(s: &Setup, who: &Address) -> i128 {
+    TokenClient::new(&s.env, &s.asset.address).balance(who)
+}
+
+// Open voting, vote `choice` with each voter and close voting
+fn decide(s: &Setup, proposal_id: u64, votes: &[(&Address, VoteChoice)]) {
+    advance(s, 1);
+    for (voter, choice) in votes {
+        s.dao.vote(voter, &proposal_id, choice, &None);
+    }
+    advance(s, s.dao.get_config().voting_period);
+}
+
+#[test]
+fn invest_accepts_i128_max() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+
+    let investor = Address::generate(&s.env);
+    s.asset.mint(&investor, &i128::MAX);
+    assert_eq!(s.dao.invest(&investor, &i128::MAX), i128::MAX);
+    assert_eq!(s.dao.get_total_raised(), i128::MAX);
+    assert_eq!(s.shares.balance(&investor), i128::MAX);
+
+    // The hard cap stops the raise before any total can wrap
+    let late = Address::generate(&s.env);
+    s.asset.mint(&late, &1);
+    assert!(s.dao.try_invest(&late, &1).is_err());
+    assert_eq!(s.dao.get_total_raised(), i128::MAX);
+}
+
+#[test]
+fn invest_with_lockup_past_u64_max_overflows() {
+    let s = setup();
+    let mut config = config(&s.env, &s.asset.address);
+    config.lockup_period = u64::MAX;
+    initialize(&s, &config);
+
+    let investor = Address::generate(&s.env);
+    s.asset.mint(&investor, &100);
+    assert_eq!(
+        s.dao.try_invest(&investor, &100),
+        Err(Ok(Error::Overflow.into()))
+    );
+    assert_eq!(s.dao.get_total_raised(), 0);
+}
+
+#[test]
+fn initialize_with_funding_period_past_u64_max_overflows() {
+    let s = setup();
+    let mut config = config(&s.env, &s.asset.address);
+    config.funding_period = u64::MAX;
+
+    let result = s.dao.try_initialize_dao(
+        &info(&s.env),
+        &1_000,
+        &Address::generate(&s.env),
+        &s.shares.address,
+        &config,
+        &Vec::new(&s.env),
+    );
+    assert_eq!(result, Err(Ok(Error::Overflow.into())));
+}
+
+#[test]
+fn initialize_twice_fails() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    assert!(s
+        .dao
+        .try_initialize_dao(
+            &info(&s.env),
+            &1_000,
+            &Address::generate(&s.env),
+            &s.shares.address,
+            &config(&s.env, &s.asset.address),
+            &Vec::new(&s.env),
+        )
+        .is_err());
+    assert_eq!(s.dao.get_factory(), s.factory);
+}
+
+#[test]
+fn mul_div_at_i128_max() {
+    let s = setup();
+    s.env.as_contract(&s.dao.address, || {
+        assert_eq!(mul_div(&s.env, i128::MAX, 1, 1), i128::MAX);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn mul_div_overflow() {
+    let s = setup();
+    s.env.as_contract(&s.dao.address, || {
+        mul_div(&s.env, i128::MAX, 2, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn mul_div_by_zero() {
+    let s = setup();
+    s.env.as_contract(&s.dao.address, || {
+        mul_div(&s.env, 1, 1, 0);
+    });
+}
+
+#[test]
+fn active_proposals_are_capped_per_proposer() {
+    let s = setup();
+    let mut config = config(&s.env, &s.asset.address);
+    config.max_active_proposals = 2;
+    initialize(&s, &config);
+    let proposer = Address::generate(&s.env);
+    let key = DataKey::ActiveProposals(proposer.clone());
+
+    propose(&s, &proposer, ProposalAction::None);
+    propose(&s, &proposer, ProposalAction::None);
+    assert!(s
+        .dao
+        .try_create_proposal(&proposer, &info(&s.env), &ProposalAction::None)
+        .is_err());
+    // Other proposers have their own cap
+    propose(&s, &Address::generate(&s.env), ProposalAction::None);
+
+    // Once voting closes the slots free up and the emptied entry is dropped
+    s.env
+        .ledger()
+        .set_timestamp(1_000 + config.voting_period + 1);
+    s.env.as_contract(&s.dao.address, || {
+        assert_eq!(count_active_proposals(&s.env, &proposer), 0);
+        assert!(!s.env.storage().persistent().has(&key));
+    });
+    propose(&s, &proposer, ProposalAction::None);
+    s.env.as_contract(&s.dao.address, || {
+        let ids: Vec<u64> = s.env.storage().persistent().get(&key).unwrap();
+        assert_eq!(ids, vec![&s.env, 3]);
+    });
+}
+
+#[test]
+fn proposal_moves_through_its_lifecycle() {
+    let s = setup();
+    let config = config(&s.env, &s.asset.address);
+    initialize(&s, &config);
+    let investor = invest(&s, 100);
+
+    let id = propose(&s, &investor, ProposalAction::None);
+    assert_eq!(s.dao.get_proposal_state(&id), ProposalStatus::Pending);
+    advance(&s, 1);
+    assert_eq!(s.dao.get_proposal_state(&id), ProposalStatus::Active);
+    s.dao.vote(&investor, &id, &VoteChoice::For, &None);
+    advance(&s, config.voting_period);
+    assert_eq!(s.dao.get_proposal_state(&id), ProposalStatus::Succeeded);
+
+    s.dao.queue(&id);
+    assert_eq!(s.dao.get_proposal_state(&id), ProposalStatus::Queued);
+    advance(&s, config.timelock_delay);
+    s.dao.execute_proposal(&id);
+    assert_eq!(s.dao.get_proposal_state(&id), ProposalStatus::Executed);
+    assert!(s.dao.get_proposal_executed(&id));
+    assert!(s.dao.try_execute_proposal(&id).is_err());
+}
+
+#[test]
+fn proposal_without_support_is_defeated() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let investor = invest(&s, 100);
+
+    let silent = propose(&s, &investor, ProposalAction::None);
+    let rejected = propose(&s, &investor, ProposalAction::None);
+    advance(&s, 1);
+    s.dao
+        .vote(&investor, &rejected, &VoteChoice::Against, &None);
+    advance(&s, s.dao.get_config().voting_period);
+
+    assert_eq!(s.dao.get_proposal_state(&silent), ProposalStatus::Defeated);
+    assert_eq!(
+        s.dao.get_proposal_state(&rejected),
+        ProposalStatus::Defeated
+    );
+    assert!(s.dao.try_queue(&rejected).is_err());
+}
+
+#[test]
+fn proposer_cancels_only_while_voting() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let investor = invest(&s, 100);
+    let stranger = Address::generate(&s.env);
+
+    let pending = propose(&s, &investor, ProposalAction::None);
+    assert!(s.dao.try_cancel_proposal(&stranger, &pending).is_err());
+    s.dao.cancel_proposal(&investor, &pending);
+    assert_eq!(
+        s.dao.get_proposal_state(&pending),
+        ProposalStatus::Cancelled
+    );
+
+    let closed = propose(&s, &investor, ProposalAction::None);
+    decide(&s, closed, &[(&investor, VoteChoice::For)]);
+    assert!(s.dao.try_cancel_proposal(&investor, &closed).is_err());
+    assert_eq!(s.dao.get_proposal_state(&closed), ProposalStatus::Succeeded);
+}
+
+#[test]
+fn guardian_vetoes_until_execution() {
+    let s = setup();
+    let creator = initialize(&s, &config(&s.env, &s.asset.address));
+    let investor = invest(&s, 1_000);
+    assert_eq!(s.dao.get_guardian(), Some(creator.clone()));
+
+    let signal = propose(&s, &investor, ProposalAction::None);
+    let payout = propose(
+        &s,
+        &investor,
+        ProposalAction::Payout(Address::generate(&s.env), 100),
+    );
+    let executed = propose(&s, &investor, ProposalAction::None);
+    let votes = [(&investor, VoteChoice::For)];
+    advance(&s, 1);
+    for id in [signal, payout, executed] {
+        s.dao.vote(&investor, &id, &VoteChoice::For, &None);
+    }
+    advance(&s, s.dao.get_config().voting_period);
+    for id in [signal, payout, executed] {
+        s.dao.queue(&id);
+    }
+    advance(&s, s.dao.get_config().timelock_delay);
+    s.dao.execute_proposal(&executed);
+
+    // A queued signalling proposal can still be vetoed ...
+    s.dao.cancel_proposal(&creator, &signal);
+    assert_eq!(s.dao.get_proposal_state(&signal), ProposalStatus::Cancelled);
+    // ... but not a queued payout, nor anything already executed
+    assert!(s.dao.try_cancel_proposal(&creator, &payout).is_err());
+    assert!(s.dao.try_cancel_proposal(&creator, &executed).is_err());
+
+    // Handing the role on takes the veto with it
+    let guardian = Address::generate(&s.env);
+    s.dao.set_guardian(&Some(guardian.clone()));
+    let next = propose(&s, &investor, ProposalAction::None);
+    decide(&s, next, &votes);
+    assert!(s.dao.try_cancel_proposal(&creator, &next).is_err());
+    s.dao.cancel_proposal(&guardian, &next);
+    assert_eq!(s.dao.get_proposal_state(&next), ProposalStatus::Cancelled);
+}
+
+#[test]
+fn queued_proposal_waits_for_its_eta() {
+    let s = setup();
+    let config = config(&s.env, &s.asset.address);
+    initialize(&s, &config);
+    let investor = invest(&s, 100);
+    let recipient = Address::generate(&s.env);
+
+    let id = propose(&s, &investor, ProposalAction::Payout(recipient.clone(), 60));
+    decide(&s, id, &[(&investor, VoteChoice::For)]);
+    let eta = s.dao.queue(&id);
+    assert_eq!(eta, s.env.ledger().timestamp() + config.timelock_delay);
+    assert_eq!(s.dao.get_proposal_eta(&id), eta);
+    assert!(s.dao.try_queue(&id).is_err());
+
+    advance(&s, config.timelock_delay - 1);
+    assert!(s.dao.try_execute_proposal(&id).is_err());
+    advance(&s, 1);
+    s.dao.execute_proposal(&id);
+    assert_eq!(balance(&s, &recipient), 60);
+}
+
+#[test]
+fn queued_proposal_expires_after_grace_period() {
+    let s = setup();
+    let config = config(&s.env, &s.asset.address);
+    initialize(&s, &config);
+    let investor = invest(&s, 100);
+
+    let id = propose(&s, &investor, ProposalAction::None);
+    decide(&s, id, &[(&investor, VoteChoice::For)]);
+    s.dao.queue(&id);
+
+    advance(&s, config.timelock_delay + config.grace_period);
+    assert_eq!(s.dao.get_proposal_state(&id), ProposalStatus::Queued);
+    advance(&s, 1);
+    assert_eq!(s.dao.get_proposal_state(&id), ProposalStatus::Expired);
+    assert!(s.dao.try_execute_proposal(&id).is_err());
+}
+
+#[test]
+fn queue_rejects_payouts_beyond_uncommitted_treasury() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let investor = invest(&s, 100);
+    let recipient = Address::generate(&s.env);
+
+    let first = propose(&s, &investor, ProposalAction::Payout(recipient.clone(), 70));
+    let second = propose(&s, &investor, ProposalAction::Payout(recipient, 70));
+    advance(&s, 1);
+    s.dao.vote(&investor, &first, &VoteChoice::For, &None);
+    s.dao.vote(&investor, &second, &VoteChoice::For, &None);
+    advance(&s, s.dao.get_config().voting_period);
+
+    s.dao.queue(&first);
+    assert!(s.dao.try_queue(&second).is_err());
+}
+
+#[test]
+fn delegate_votes_with_delegated_shares() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let passive = invest(&s, 300);
+    let active = invest(&s, 100);
+
+    s.shares.delegate(&passive, &active);
+    assert_eq!(s.dao.get_voting_power(&active), 400);
+    assert_eq!(s.dao.get_voting_power(&passive), 0);
+
+    let id = propose(&s, &active, ProposalAction::None);
+    advance(&s, 1);
+    s.dao.vote(&active, &id, &VoteChoice::For, &None);
+    assert!(s
+        .dao
+        .try_vote(&passive, &id, &VoteChoice::Against, &None)
+        .is_err());
+    assert_eq!(s.dao.get_proposal_tally(&id), (400, 0, 0));
+    assert_eq!(s.dao.get_ballot(&id, &active).unwrap().weight, 400);
+}
+
+#[test]
+fn votes_are_weighted_at_the_voting_snapshot() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let early = invest(&s, 100);
+    let id = propose(&s, &early, ProposalAction::None);
+
+    // Shares bought or received once voting has started carry no weight
+    advance(&s, 1);
+    let late = invest(&s, 500);
+    let receiver = Address::generate(&s.env);
+    s.shares.transfer(&early, &receiver, &40);
+
+    assert!(s.dao.try_vote(&late, &id, &VoteChoice::For, &None).is_err());
+    assert!(s
+        .dao
+        .try_vote(&receiver, &id, &VoteChoice::For, &None)
+        .is_err());
+    s.dao.vote(&early, &id, &VoteChoice::For, &None);
+    assert!(s
+        .dao
+        .try_vote(&early, &id, &VoteChoice::For, &None)
+        .is_err());
+    assert_eq!(s.dao.get_proposal_tally(&id), (100, 0, 0));
+}
+
+#[test]
+fn abstain_counts_toward_quorum_but_not_approval() {
+    let s = setup();
+    // 10% quorum of the 1000 share supply
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let abstainer = invest(&s, 95);
+    let supporter = invest(&s, 5);
+    let bystander = invest(&s, 900);
+
+    let short = propose(&s, &supporter, ProposalAction::None);
+    let reached = propose(&s, &supporter, ProposalAction::None);
+    advance(&s, 1);
+    s.dao.vote(&supporter, &short, &VoteChoice::For, &None);
+    s.dao.vote(&supporter, &reached, &VoteChoice::For, &None);
+    s.dao
+        .vote(&abstainer, &reached, &VoteChoice::Abstain, &None);
+    advance(&s, s.dao.get_config().voting_period);
+
+    assert_eq!(s.dao.get_proposal_tally(&reached), (5, 0, 95));
+    assert_eq!(s.dao.get_proposal_state(&short), ProposalStatus::Defeated);
+    assert_eq!(
+        s.dao.get_proposal_state(&reached),
+        ProposalStatus::Succeeded
+    );
+
+    // Abstaining alone approves nothing
+    let only_abstain = propose(&s, &supporter, ProposalAction::None);
+    decide(&s, only_abstain, &[(&bystander, VoteChoice::Abstain)]);
+    assert_eq!(
+        s.dao.get_proposal_state(&only_abstain),
+        ProposalStatus::Defeated
+    );
+}
+
+#[test]
+fn ballot_keeps_the_reason() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let investor = invest(&s, 100);
+    let id = propose(&s, &investor, ProposalAction::None);
+    advance(&s, 1);
+
+    let long = String::from_bytes(&s.env, &[b'x'; MAX_REASON_LEN as usize + 1]);
+    assert!(s
+        .dao
+        .try_vote(&investor, &id, &VoteChoice::Against, &Some(long))
+        .is_err());
+
+    let reason = String::from_str(&s.env, "Too expensive");
+    s.dao
+        .vote(&investor, &id, &VoteChoice::Against, &Some(reason.clone()));
+    assert_eq!(
+        s.dao.get_ballot(&id, &investor),
+        Some(Ballot {
+            choice: VoteChoice::Against,
+            weight: 100,
+            reason,
+        })
+    );
+}
+
+#[test]
+fn ragequit_pays_share_of_uncommitted_treasury_during_timelock() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let supporter = invest(&s, 600);
+    let dissenter = invest(&s, 400);
+    let recipient = Address::generate(&s.env);
+
+    // No window before anything is queued
+    assert!(s.dao.try_ragequit(&dissenter, &100).is_err());
+
+    let id = propose(
+        &s,
+        &supporter,
+        ProposalAction::Payout(recipient.clone(), 500),
+    );
+    decide(
+        &s,
+        id,
+        &[
+            (&supporter, VoteChoice::For),
+            (&dissenter, VoteChoice::Against),
+        ],
+    );
+    s.dao.queue(&id);
+
+    // Those who voted for the proposal stay in
+    assert!(s.dao.try_ragequit(&supporter, &100).is_err());
+
+    // 500 of the 1000 treasury is committed, so 400 of 1000 shares take 200
+    assert_eq!(s.dao.ragequit(&dissenter, &400), 200);
+    assert_eq!(balance(&s, &dissenter), 200);
+    assert_eq!(s.shares.balance(&dissenter), 0);
+    assert_eq!(s.shares.total_supply(), 600);
+    assert_eq!(s.dao.get_total_raised(), 600);
+    assert_eq!(s.dao.get_investments().get(dissenter.clone()), None);
+
+    // The queued payout is still covered
+    advance(&s, s.dao.get_config().timelock_delay);
+    s.dao.execute_proposal(&id);
+    assert_eq!(balance(&s, &recipient), 500);
+    assert_eq!(balance(&s, &s.dao.address), 300);
+}
+
+#[test]
+fn ragequit_closes_at_the_eta() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let supporter = invest(&s, 600);
+    let silent = invest(&s, 400);
+
+    let id = propose(&s, &supporter, ProposalAction::None);
+    decide(&s, id, &[(&supporter, VoteChoice::For)]);
+    s.dao.queue(&id);
+
+    advance(&s, s.dao.get_config().timelock_delay - 1);
+    assert_eq!(s.dao.ragequit(&silent, &100), 100);
+    advance(&s, 1);
+    assert!(s.dao.try_ragequit(&silent, &100).is_err());
+    assert_eq!(s.shares.balance(&silent), 300);
+}
+
+#[test]
+fn milestone_release_pays_only_its_tranche() {
+    let s = setup();
+    let creator =
+        initialize_with_milestones(&s, &config(&s.env, &s.asset.address), &milestones(&s.env));
+    let investor = invest(&s, 1_000);
+
+    assert!(s
+        .dao
+        .try_create_proposal(
+            &investor,
+            &info(&s.env),
+            &ProposalAction::ReleaseMilestone(1)
+        )
+        .is_err());
+    let id = propose(&s, &investor, ProposalAction::ReleaseMilestone(0));
+    decide(&s, id, &[(&investor, VoteChoice::For)]);
+    s.dao.queue(&id);
+    advance(&s, s.dao.get_config().timelock_delay);
+    s.dao.execute_proposal(&id);
+
+    assert_eq!(balance(&s, &creator), 400);
+    assert_eq!(balance(&s, &s.dao.address), 600);
+    assert_eq!(s.dao.get_next_milestone(), 1);
+}
+
+#[test]
+fn payout_cannot_spend_unreleased_tranches() {
+    let s = setup();
+    let creator =
+        initialize_with_milestones(&s, &config(&s.env, &s.asset.address), &milestones(&s.env));
+    let investor = invest(&s, 1_000);
+    // Profit paid in on top of the raise is free to spend
+    s.asset.mint(&s.dao.address, &50);
+
+    let to_creator = propose(&s, &investor, ProposalAction::Payout(creator.clone(), 400));
+    let within = propose(&s, &investor, ProposalAction::Payout(creator.clone(), 50));
+    advance(&s, 1);
+    s.dao.vote(&investor, &to_creator, &VoteChoice::For, &None);
+    s.dao.vote(&investor, &within, &VoteChoice::For, &None);
+    advance(&s, s.dao.get_config().voting_period);
+
+    assert!(s.dao.try_queue(&to_creator).is_err());
+    s.dao.queue(&within);
+    advance(&s, s.dao.get_config().timelock_delay);
+    s.dao.execute_proposal(&within);
+    assert_eq!(balance(&s, &creator), 50);
+    assert_eq!(balance(&s, &s.dao.address), 1_000);
+}
+
+#[test]
+fn missed_milestone_refunds_the_rest_pro_rata() {
+    let s = setup();
+    initialize_with_milestones(&s, &config(&s.env, &s.asset.address), &milestones(&s.env));
+    let large = invest(&s, 600);
+    let small = invest(&s, 400);
+
+    let release = propose(&s, &large, ProposalAction::ReleaseMilestone(0));
+    decide(&s, release, &[(&large, VoteChoice::For)]);
+    s.dao.queue(&release);
+    advance(&s, s.dao.get_config().timelock_delay);
+    s.dao.execute_proposal(&release);
+
+    // No refund while the next milestone is not yet due
+    assert!(s
+        .dao
+        .try_create_proposal(&small, &info(&s.env), &ProposalAction::Refund)
+        .is_err());
+    s.env.ledger().set_timestamp(3_001);
+    let refund = propose(&s, &small, ProposalAction::Refund);
+    decide(&s, refund, &[(&large, VoteChoice::For)]);
+    s.dao.queue(&refund);
+    advance(&s, s.dao.get_config().timelock_delay);
+    s.dao.execute_proposal(&refund);
+    assert_eq!(s.dao.get_status(), DaoStatus::Refunding);
+
+    // The 600 left is split over the 1000 shares at the time of the vote
+    assert_eq!(s.dao.claim_refund(&large), 360);
+    assert_eq!(s.dao.claim_refund(&small), 240);
+    assert_eq!(balance(&s, &large), 360);
+    assert_eq!(s.shares.total_supply(), 0);
+    assert_eq!(balance(&s, &s.dao.address), 0);
+    assert!(s.dao.try_claim_refund(&small).is_err());
+    assert!(s.dao.try_invest(&small, &1).is_err());
+}
+
+#[test]
+fn allowlist_gates_investing_and_share_transfers() {
+    let s = setup();
+    let creator = initialize(&s, &config(&s.env, &s.asset.address));
+    let holder = invest(&s, 100);
+    let listed = Address::generate(&s.env);
+    let unlisted = Address::generate(&s.env);
+    s.asset.mint(&unlisted, &100);
+
+    s.dao.set_investor_gate(&InvestorGate::Allowlist);
+    assert_eq!(s.env.auths()[0].0, creator);
+    s.dao.allow_investor(&holder);
+    s.dao.allow_investor(&listed);
+    assert!(s.dao.is_investor_allowed(&listed));
+    assert!(!s.dao.is_investor_allowed(&unlisted));
+
+    assert!(s.dao.try_invest(&unlisted, &100).is_err());
+    assert!(s.shares.try_transfer(&holder, &unlisted, &10).is_err());
+    s.shares.transfer(&holder, &listed, &10);
+    assert_eq!(s.shares.balance(&listed), 10);
+
+    s.dao.disallow_investor(&listed);
+    assert!(!s.dao.is_investor_allowed(&listed));
+    s.env.as_contract(&s.dao.address, || {
+        assert!(!s
+            .env
+            .storage()
+            .persistent()
+            .has(&DataKey::Allowed(listed.clone())));
+    });
+
+    s.dao.set_investor_gate(&InvestorGate::Open);
+    s.dao.invest(&unlisted, &100);
+    assert_eq!(s.shares.balance(&unlisted), 100);
+}
+
+#[test]
+fn reinvested_profit_is_counted_apart_from_the_raise() {
+    let s = setup();
+    let mut config = config(&s.env, &s.asset.address);
+    config.hard_cap = 1_000;
+    initialize(&s, &config);
+    let investor = invest(&s, 1_000);
+    let unlisted = Address::generate(&s.env);
+
+    s.dao.reinvest_profit(&investor, &200);
+    assert_eq!(s.env.auths()[0].0, config.profit_distributor);
+    assert_eq!(s.shares.balance(&investor), 1_200);
+    assert_eq!(s.dao.get_total_raised(), 1_000);
+    assert_eq!(s.dao.get_total_reinvested(), 200);
+
+    s.dao.set_investor_gate(&InvestorGate::Allowlist);
+    assert!(s.dao.try_reinvest_profit(&unlisted, &10).is_err());
+    assert_eq!(s.dao.get_total_reinvested(), 200);
+}
+
+#[test]
+fn vesting_grant_claims_nothing_before_cliff_then_linearly() {
+    let s = setup();
+    initialize(&s, &config(&s.env, &s.asset.address));
+    let investor = invest(&s, 1_000);
+    let team = Address::generate(&s.env);
+
+    let grant = VestingGrant {
+        beneficiary: team.clone(),
+        amount: 1_000,
+        cliff: 100,
+        duration: 400,
+    };
+    let id = propose(&s, &investor, ProposalAction::GrantVesting(grant.clone()));
+    decide(&s, id, &[(&investor, VoteChoice::For)]);
+    s.dao.queue(&id);
+    advance(&s, s.dao.get_config().timelock_delay);
+    s.dao.execute_proposal(&id);
+    let start = s.env.ledger().timestamp();
+    assert_eq!(
+        s.dao.get_vesting_schedules(&team),
+        vec![
+            &s.env,
+            VestingSchedule {
+                grant,
+                start,
+                claimed: 0,
+            }
+        ]
+    );
+
+    advance(&s, 99);
+    assert_eq!(s.dao.get_claimable_vested(&team), 0);
+    assert!(s.dao.try_claim_vested(&team).is_err());
+
+    advance(&s, 1);
+    assert_eq!(s.dao.claim_vested(&team), 250);
+    advance(&s, 100);
+    assert_eq!(s.dao.get_claimable_vested(&team), 250);
+    assert_eq!(s.dao.claim_vested(&team), 250);
+    assert_eq!(s.shares.balance(&team), 500);
+
+    advance(&s, 1_000);
+    assert_eq!(s.dao.claim_vested(&team), 500);
+    assert_eq!(s.shares.balance(&team), 1_000);
+    assert!(s.dao.try_claim_vested(&team).is_err());
+}
+
+#[test]
+fn only_the_profit_distributor_records_distributions() {
+    let s = setup();
+    let config = config(&s.env, &s.asset.address);
+    initialize(&s, &config);
+
+    s.dao.record_distribution(&1_000, &50);
+    s.dao.record_distribution(&2_000, &70);
+    assert_eq!(s.env.auths()[0].0, config.profit_distributor);
+    assert_eq!(s.dao.get_distribution_count(), 2);
+    assert_eq!(
+        s.dao.get_distribution_history(&1, &10),
+        vec![&s.env, (2_000, 70)]
+    );
+    assert!(s
+        .dao
+        .get_distribution_history(&u32::MAX, &u32::MAX)
+        .is_empty());
+
+    s.env.mock_auths(&[]);
+    assert!(s.dao.try_record_distribution(&3_000, &10).is_err());
+    assert_eq!(s.dao.get_distribution_count(), 2);
+}
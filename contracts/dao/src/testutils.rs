@@ -0,0 +1,122 @@
+// Fixture shared by the DAO's tests and those of contracts built on it: an
+// uninitialized DAO with its profit token, accepted asset and a stand-in
+// factory, at ledger timestamp 1000
+use crate::{
+    Content, ContentBody, DAOContract, DAOContractClient, DaoConfig, DaoStatus, Milestone,
+};
+use profit_token::{ProfitTokenContract, ProfitTokenContractClient};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, BytesN, Env, String, Vec,
+};
+
+// Stands in for the factory: takes the DAO's summary updates and, like the
+// factory's registry, knows which DAOs it deployed
+#[contract]
+pub struct MockFactory;
+
+#[contractimpl]
+impl MockFactory {
+    pub fn register_dao(env: Env, dao: Address, dao_id: u64) {
+        env.storage().instance().set(&dao, &dao_id);
+    }
+
+    pub fn get_dao_id(env: Env, dao: Address) -> Option<u64> {
+        env.storage().instance().get(&dao)
+    }
+
+    pub fn update_dao_summary(_env: Env, _dao: Address, _status: DaoStatus, _total_raised: i128) {}
+}
+
+pub struct Setup<'a> {
+    pub env: Env,
+    pub dao: DAOContractClient<'a>,
+    pub shares: ProfitTokenContractClient<'a>,
+    pub asset: StellarAssetClient<'a>,
+    pub factory: Address,
+}
+
+pub fn config(env: &Env, asset: &Address) -> DaoConfig {
+    DaoConfig {
+        voting_delay: 0,
+        voting_period: 100,
+        timelock_delay: 10,
+        grace_period: 100,
+        quorum_bps: 1_000,
+        approval_threshold_bps: 5_000,
+        proposal_threshold: 0,
+        max_active_proposals: 5,
+        funding_period: 1_000,
+        min_investment: 1,
+        max_investment: i128::MAX,
+        hard_cap: i128::MAX,
+        lockup_period: 0,
+        accepted_asset: asset.clone(),
+        profit_distributor: Address::generate(env),
+        compliance_enabled: false,
+        token_name: String::from_str(env, "Share"),
+        token_symbol: String::from_str(env, "SHR"),
+    }
+}
+
+pub fn info(env: &Env) -> Content {
+    Content {
+        title: String::from_str(env, "Test DAO"),
+        body: ContentBody::None,
+        content_hash: BytesN::from_array(env, &[0; 32]),
+    }
+}
+
+pub fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let factory = env.register(MockFactory, ());
+    let dao_id = env.register(DAOContract, (factory.clone(),));
+    MockFactoryClient::new(&env, &factory).register_dao(&dao_id, &0);
+    let token_id = env.register(ProfitTokenContract, ());
+    let asset_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    Setup {
+        dao: DAOContractClient::new(&env, &dao_id),
+        shares: ProfitTokenContractClient::new(&env, &token_id),
+        asset: StellarAssetClient::new(&env, &asset_id),
+        factory,
+        env,
+    }
+}
+
+// Initialize the DAO with a 1000 goal and no milestones; returns the creator
+pub fn initialize(s: &Setup, config: &DaoConfig) -> Address {
+    initialize_with_milestones(s, config, &Vec::new(&s.env))
+}
+
+pub fn initialize_with_milestones(
+    s: &Setup,
+    config: &DaoConfig,
+    milestones: &Vec<Milestone>,
+) -> Address {
+    let creator = Address::generate(&s.env);
+    s.dao.initialize_dao(
+        &info(&s.env),
+        &1_000,
+        &creator,
+        &s.shares.address,
+        config,
+        milestones,
+    );
+    creator
+}
+
+// A fresh investor holding `amount` shares bought through `invest`
+pub fn invest(s: &Setup, amount: i128) -> Address {
+    let investor = Address::generate(&s.env);
+    s.asset.mint(&investor, &amount);
+    s.dao.invest(&investor, &amount);
+    investor
+}
@@ -0,0 +1,47 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::testutils::Address as _;
+
+fn setup<'a>(env: &Env) -> PaymentContractClient<'a> {
+    env.mock_all_auths();
+    let payments = PaymentContractClient::new(env, &env.register(PaymentContract, ()));
+    payments.initialize();
+    payments
+}
+
+#[test]
+fn deposit_up_to_i128_max() {
+    let env = Env::default();
+    let payments = setup(&env);
+    let holder = Address::generate(&env);
+
+    payments.deposit(&holder, &(i128::MAX - 1));
+    payments.deposit(&holder, &1);
+    assert_eq!(payments.get_balance(&holder), i128::MAX);
+}
+
+#[test]
+fn deposit_past_i128_max_overflows() {
+    let env = Env::default();
+    let payments = setup(&env);
+    let holder = Address::generate(&env);
+    payments.deposit(&holder, &i128::MAX);
+
+    assert_eq!(
+        payments.try_deposit(&holder, &1),
+        Err(Ok(Error::Overflow.into()))
+    );
+    assert_eq!(payments.get_balance(&holder), i128::MAX);
+}
+
+#[test]
+fn withdraw_above_balance_fails() {
+    let env = Env::default();
+    let payments = setup(&env);
+    let holder = Address::generate(&env);
+    payments.deposit(&holder, &10);
+
+    assert!(payments.try_withdraw(&holder, &11).is_err());
+    payments.withdraw(&holder, &10);
+    assert_eq!(payments.get_balance(&holder), 0);
+}
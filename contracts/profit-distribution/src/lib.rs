@@ -1,18 +1,248 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, Vec};
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error,
+    symbol_short, token, vec, Address, Bytes, Env, Vec,
+};
 // Replace these imports with the actual client paths in your workspace
-use dao::DAOContractClient;
+use dao::{DAOContractClient, DaoStatus};
 use profit_token::ProfitTokenContractClient;
 use soroban_sdk::IntoVal;
+
+// Typed failures of payout arithmetic
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    Overflow = 1,
+    DivisionByZero = 2,
+}
+
+fn add(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+fn sub(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_sub(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+// a * b / c without silently wrapping the intermediate product
+fn mul_div(env: &Env, a: i128, b: i128, c: i128) -> i128 {
+    if c == 0 {
+        panic_with_error!(env, Error::DivisionByZero);
+    }
+    a.checked_mul(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+        / c
+}
+
+// Per-DAO and per-round records, each in its own persistent entry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // (DAO, round ID) -> DistributionRound
+    Round(Address, u64),
+    // Number of rounds opened for a DAO
+    RoundCount(Address),
+    // DAO -> DistributionSchedule
+    Schedule(Address),
+    // (DAO, investor) -> true while the investor's payouts are reinvested
+    Reinvest(Address, Address),
+    // (DAO, investor, asset) -> payout held for `claim`
+    Claimable(Address, Address, Address),
+}
+
+// A funded payout to a DAO's shareholders, paid out over one or more batches.
+// Each holder gets their share of `amount` by their balance at `snapshot`,
+// out of `total_shares`, the supply at that time. Holders are visited in the
+// order the profit token first saw them; `cursor` is the position of the next
+// one to pay, so each holder is paid at most once.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistributionRound {
+    pub funder: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub snapshot: u64,
+    pub total_shares: i128,
+    pub cursor: u32,
+    pub paid: i128,
+    pub complete: bool,
+}
+
+fn load_round(env: &Env, dao: &Address, round_id: u64) -> DistributionRound {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Round(dao.clone(), round_id))
+        .expect("Round does not exist")
+}
+
+fn save_round(env: &Env, dao: &Address, round_id: u64, round: &DistributionRound) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Round(dao.clone(), round_id), round);
+}
+
+// Largest keeper fee a schedule may offer, in basis points of each payout
+const MAX_KEEPER_FEE_BPS: u32 = 100;
+const BPS_DENOMINATOR: i128 = 10_000;
+// Holders paid by the batch `poke` runs when it opens a scheduled round
+const POKE_BATCH_SIZE: u32 = 25;
+
+// Recurring payout for a DAO: every `interval` seconds, `amount` of `asset`
+// is pulled from `source` (which must have approved this contract) and paid
+// to the DAO's investors, less a fee for whoever triggers it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistributionSchedule {
+    pub source: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub interval: u64,
+    pub keeper_fee_bps: u32,
+    pub next_run: u64,
+}
+
+// The part of the factory interface used to tell genuine DAOs from lookalikes
+#[contractclient(name = "DAOFactoryClient")]
+pub trait DAOFactoryInterface {
+    fn get_dao_id(env: Env, dao: Address) -> Option<u64>;
+}
+
+// Panic unless `dao` was deployed by our factory and pays its profits through
+// this contract. Otherwise a lookalike DAO could report its own token and
+// holders, and be paid out of escrow funded for other DAOs.
+fn check_dao<'a>(env: &'a Env, dao: &'a Address) -> DAOContractClient<'a> {
+    let factory: Address = env
+        .storage()
+        .instance()
+        .get(&Bytes::from_slice(env, b"factory"))
+        .unwrap();
+    assert!(
+        DAOFactoryClient::new(env, &factory)
+            .get_dao_id(dao)
+            .is_some(),
+        "DAO was not deployed by the factory"
+    );
+
+    let dao_client = DAOContractClient::new(env, dao);
+    assert!(
+        dao_client.get_config().profit_distributor == env.current_contract_address(),
+        "DAO does not distribute profits through this contract"
+    );
+    dao_client
+}
+
+// Register a round over funds this contract already holds; returns its ID
+fn start_round(env: &Env, dao: &Address, funder: Address, asset: Address, amount: i128) -> u64 {
+    let dao_client = check_dao(env, dao);
+    let token_id = dao_client.get_token_contract();
+    // Balances as of the previous timestamp are final; the current one's can
+    // still change within this ledger
+    let snapshot = env.ledger().timestamp().saturating_sub(1);
+    let total_shares =
+        ProfitTokenContractClient::new(env, &token_id).get_past_total_supply(&snapshot);
+    assert!(total_shares > 0, "DAO has no shares outstanding");
+
+    let persistent = env.storage().persistent();
+    let count_key = DataKey::RoundCount(dao.clone());
+    let round_id: u64 = persistent.get(&count_key).unwrap_or(0);
+    persistent.set(
+        &count_key,
+        &round_id
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow)),
+    );
+
+    save_round(
+        env,
+        dao,
+        round_id,
+        &DistributionRound {
+            funder,
+            asset,
+            amount,
+            snapshot,
+            total_shares,
+            cursor: 0,
+            paid: 0,
+            complete: false,
+        },
+    );
+    env.events()
+        .publish((symbol_short!("round"), dao.clone(), round_id), amount);
+    round_id
+}
+
+// Pay the next `max_count` holders of a round, completing it after the last
+fn pay_batch(env: &Env, dao: &Address, round_id: u64, max_count: u32) -> DistributionRound {
+    assert!(max_count > 0, "Batch size must be positive");
+    let mut round = load_round(env, dao, round_id);
+    assert!(!round.complete, "Round is already complete");
+
+    let dao_client = DAOContractClient::new(env, dao);
+    let shares = ProfitTokenContractClient::new(env, &dao_client.get_token_contract());
+    let asset = token::TokenClient::new(env, &round.asset);
+    let this = env.current_contract_address();
+    let persistent = env.storage().persistent();
+
+    // Holders who first received shares after the snapshot are paid nothing
+    let holders = shares.get_holders(&round.cursor, &max_count);
+    for investor in holders.iter() {
+        let bal = shares.get_past_balance(&investor, &round.snapshot);
+        let payout = mul_div(env, round.amount, bal, round.total_shares);
+        if payout > 0 {
+            // Never pay out more than this round escrowed
+            round.paid = add(env, round.paid, payout);
+            assert!(round.paid <= round.amount, "Payouts exceed round amount");
+            // investors rolling profits over collect them through `claim`
+            if persistent.has(&DataKey::Reinvest(dao.clone(), investor.clone())) {
+                let key = DataKey::Claimable(dao.clone(), investor, round.asset.clone());
+                let owed: i128 = persistent.get(&key).unwrap_or(0);
+                persistent.set(&key, &add(env, owed, payout));
+            } else {
+                asset.transfer(&this, &investor, &payout);
+            }
+        }
+    }
+    round.cursor += holders.len();
+
+    if round.cursor == shares.holder_count() {
+        round.complete = true;
+        let dust = sub(env, round.amount, round.paid);
+        if dust > 0 {
+            asset.transfer(&this, &round.funder, &dust);
+        }
+        dao_client.record_distribution(&env.ledger().timestamp(), &round.paid);
+        env.events().publish(
+            (symbol_short!("round_end"), dao.clone(), round_id),
+            round.paid,
+        );
+    }
+
+    save_round(env, dao, round_id, &round);
+    round
+}
+
 #[contract]
 pub struct ProfitDistributionContract;
 
 #[contractimpl]
 impl ProfitDistributionContract {
+    // Record the factory whose DAOs this contract pays profits for
+    pub fn __constructor(env: Env, factory: Address) {
+        env.storage()
+            .instance()
+            .set(&Bytes::from_slice(&env, b"factory"), &factory);
+    }
+
     // Distribute `profit_amount` of native XLM to investors
     // dao_addr: the on‐chain address of a DAOContract instance
-    pub fn distribute(env: Env, dao_addr: Address, profit_amount: u64) {
+    pub fn distribute(env: Env, dao_addr: Address, profit_amount: i128) {
         // 1. Fetch investor list
+        assert!(profit_amount > 0, "Amount must be positive");
+
         let dao = DAOContractClient::new(&env, &dao_addr);
         let investors: Vec<Address> = dao.get_investors();
 
@@ -27,7 +257,7 @@ impl ProfitDistributionContract {
         for inv in investors.iter() {
             let bal: i128 = token.balance(&inv);
             // payout = profit_amount * bal / total_shares
-            let payout = (profit_amount as i128 * bal) / total_shares;
+            let payout = mul_div(&env, profit_amount, bal, total_shares);
 
             // send XLM using proper invoke_contract format
             // Note: Replace with the actual payment contract address
@@ -40,4 +270,219 @@ impl ProfitDistributionContract {
             env.invoke_contract::<()>(&payment_address, &symbol_short!("transfer"), args);
         }
     }
+
+    // Escrow `amount` of `asset` from `funder` for a new round paying the
+    // holders of `dao`'s shares pro rata to their balances at opening; returns
+    // the round ID
+    pub fn open_round(
+        env: Env,
+        dao: Address,
+        funder: Address,
+        asset: Address,
+        amount: i128,
+    ) -> u64 {
+        funder.require_auth();
+        assert!(amount > 0, "Amount must be positive");
+
+        token::TokenClient::new(&env, &asset).transfer(
+            &funder,
+            &env.current_contract_address(),
+            &amount,
+        );
+        start_round(&env, &dao, funder, asset, amount)
+    }
+
+    // Pay up to `max_count` holders of a round, starting at `cursor`, which
+    // must be where the previous batch stopped. Once the last holder is
+    // paid the round completes, rounding dust goes back to the funder and the
+    // payout is recorded in the DAO's distribution history.
+    pub fn distribute_batch(
+        env: Env,
+        dao: Address,
+        round_id: u64,
+        cursor: u32,
+        max_count: u32,
+    ) -> DistributionRound {
+        assert!(
+            cursor == load_round(&env, &dao, round_id).cursor,
+            "Cursor does not match round progress"
+        );
+        pay_batch(&env, &dao, round_id, max_count)
+    }
+
+    // Set up recurring payouts for `dao`, the first one due `interval` seconds
+    // from now. Needs the DAO creator and the funding source to sign.
+    pub fn set_schedule(
+        env: Env,
+        dao: Address,
+        source: Address,
+        asset: Address,
+        amount: i128,
+        interval: u64,
+        keeper_fee_bps: u32,
+    ) {
+        check_dao(&env, &dao).get_creator().require_auth();
+        source.require_auth();
+        assert!(amount > 0, "Amount must be positive");
+        assert!(interval > 0, "Interval must be positive");
+        assert!(
+            keeper_fee_bps <= MAX_KEEPER_FEE_BPS,
+            "Keeper fee exceeds maximum"
+        );
+
+        let next_run = env
+            .ledger()
+            .timestamp()
+            .checked_add(interval)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        env.storage().persistent().set(
+            &DataKey::Schedule(dao),
+            &DistributionSchedule {
+                source,
+                asset,
+                amount,
+                interval,
+                keeper_fee_bps,
+                next_run,
+            },
+        );
+    }
+
+    // Stop recurring payouts for `dao`; the DAO creator signs
+    pub fn cancel_schedule(env: Env, dao: Address) {
+        DAOContractClient::new(&env, &dao)
+            .get_creator()
+            .require_auth();
+
+        let persistent = env.storage().persistent();
+        let key = DataKey::Schedule(dao);
+        assert!(persistent.has(&key), "No schedule for DAO");
+        persistent.remove(&key);
+    }
+
+    pub fn get_schedule(env: Env, dao: Address) -> Option<DistributionSchedule> {
+        env.storage().persistent().get(&DataKey::Schedule(dao))
+    }
+
+    // Run `dao`'s scheduled payout if it is due. Anyone may call this; the
+    // keeper is paid the schedule's fee and the round's first batch is paid
+    // straight away. Missed periods can be caught up with further pokes.
+    // Returns the ID of the round opened.
+    pub fn poke(env: Env, dao: Address, keeper: Address) -> u64 {
+        keeper.require_auth();
+
+        let persistent = env.storage().persistent();
+        let key = DataKey::Schedule(dao.clone());
+        let mut schedule: DistributionSchedule = persistent.get(&key).expect("No schedule for DAO");
+        assert!(
+            env.ledger().timestamp() >= schedule.next_run,
+            "Distribution is not due yet"
+        );
+        schedule.next_run = schedule
+            .next_run
+            .checked_add(schedule.interval)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        persistent.set(&key, &schedule);
+
+        let this = env.current_contract_address();
+        let asset = token::TokenClient::new(&env, &schedule.asset);
+        asset.transfer_from(&this, &schedule.source, &this, &schedule.amount);
+
+        let fee = mul_div(
+            &env,
+            schedule.amount,
+            schedule.keeper_fee_bps as i128,
+            BPS_DENOMINATOR,
+        );
+        if fee > 0 {
+            asset.transfer(&this, &keeper, &fee);
+        }
+
+        let round_id = start_round(
+            &env,
+            &dao,
+            schedule.source,
+            schedule.asset,
+            sub(&env, schedule.amount, fee),
+        );
+        pay_batch(&env, &dao, round_id, POKE_BATCH_SIZE);
+        round_id
+    }
+
+    // Choose whether `investor`'s future payouts from `dao` are held for
+    // `claim` and reinvested instead of being sent out by each batch
+    pub fn set_reinvest(env: Env, dao: Address, investor: Address, enabled: bool) {
+        investor.require_auth();
+
+        let key = DataKey::Reinvest(dao, investor);
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    pub fn get_reinvest(env: Env, dao: Address, investor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Reinvest(dao, investor))
+    }
+
+    // Payout in `asset` held for `investor` from `dao`'s rounds
+    pub fn get_claimable(env: Env, dao: Address, investor: Address, asset: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimable(dao, investor, asset))
+            .unwrap_or(0)
+    }
+
+    // Collect `investor`'s held payout in `asset`. While reinvestment is on,
+    // `asset` is the one the DAO raises in, the DAO is not refunding and the
+    // investor passes its investor gate, the payout goes to the DAO's
+    // treasury and new profit tokens are minted to the investor in its place;
+    // otherwise it is paid out to the investor.
+    // Returns the amount reinvested.
+    pub fn claim(env: Env, dao: Address, investor: Address, asset: Address) -> i128 {
+        investor.require_auth();
+
+        let persistent = env.storage().persistent();
+        let key = DataKey::Claimable(dao.clone(), investor.clone(), asset.clone());
+        let amount: i128 = persistent.get(&key).unwrap_or(0);
+        assert!(amount > 0, "Nothing to claim");
+        persistent.remove(&key);
+
+        let this = env.current_contract_address();
+        let asset_client = token::TokenClient::new(&env, &asset);
+        let dao_client = DAOContractClient::new(&env, &dao);
+        let reinvest = Self::get_reinvest(env.clone(), dao.clone(), investor.clone())
+            && dao_client.get_config().accepted_asset == asset
+            && dao_client.get_status() != DaoStatus::Refunding
+            && dao_client.is_investor_allowed(&investor);
+        if !reinvest {
+            asset_client.transfer(&this, &investor, &amount);
+            return 0;
+        }
+
+        asset_client.transfer(&this, &dao, &amount);
+        dao_client.reinvest_profit(&investor, &amount);
+        env.events()
+            .publish((symbol_short!("reinvest"), dao, investor), amount);
+        amount
+    }
+
+    // Get a round, including its progress and completion status
+    pub fn get_round(env: Env, dao: Address, round_id: u64) -> DistributionRound {
+        load_round(&env, &dao, round_id)
+    }
+
+    // Number of rounds opened for `dao`
+    pub fn get_round_count(env: Env, dao: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RoundCount(dao))
+            .unwrap_or(0)
+    }
 }
+
+#[cfg(test)]
+mod test;
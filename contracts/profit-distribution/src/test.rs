@@ -0,0 +1,248 @@
+#![cfg(test)]
+use super::*;
+use dao::testutils::{config, initialize, invest, setup as setup_dao, MockFactoryClient, Setup};
+use dao::{DaoConfig, InvestorGate};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Ledger},
+    token::TokenClient,
+    vec,
+};
+
+// A DAO that pays its profits through a fresh distribution contract
+fn setup<'a>() -> (Setup<'a>, ProfitDistributionContractClient<'a>) {
+    let s = setup_dao();
+    let distribution = ProfitDistributionContractClient::new(
+        &s.env,
+        &s.env
+            .register(ProfitDistributionContract, (s.factory.clone(),)),
+    );
+    let mut config = config(&s.env, &s.asset.address);
+    config.profit_distributor = distribution.address.clone();
+    initialize(&s, &config);
+    (s, distribution)
+}
+
+// A lookalike DAO whose token claims `holder` owns every share
+#[contract]
+struct FakeDao;
+
+#[contractimpl]
+impl FakeDao {
+    pub fn __constructor(env: Env, config: DaoConfig, holder: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("config"), &config);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("holder"), &holder);
+    }
+
+    pub fn get_config(env: Env) -> DaoConfig {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("config"))
+            .unwrap()
+    }
+
+    // The DAO doubles as its own token
+    pub fn get_token_contract(env: Env) -> Address {
+        env.current_contract_address()
+    }
+
+    pub fn get_past_total_supply(_env: Env, _timestamp: u64) -> i128 {
+        1
+    }
+
+    pub fn holder_count(_env: Env) -> u32 {
+        1
+    }
+
+    pub fn get_holders(env: Env, _start: u32, _limit: u32) -> Vec<Address> {
+        let holder: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("holder"))
+            .unwrap();
+        vec![&env, holder]
+    }
+
+    pub fn get_past_balance(_env: Env, _who: Address, _timestamp: u64) -> i128 {
+        1_000
+    }
+
+    pub fn record_distribution(_env: Env, _timestamp: u64, _amount: i128) {}
+}
+
+fn open_round(s: &Setup, distribution: &ProfitDistributionContractClient, amount: i128) -> u64 {
+    let funder = Address::generate(&s.env);
+    s.asset.mint(&funder, &amount);
+    distribution.open_round(&s.dao.address, &funder, &s.asset.address, &amount)
+}
+
+#[test]
+fn shares_moved_between_batches_are_paid_once() {
+    let (s, distribution) = setup();
+    let a = invest(&s, 60);
+    let b = invest(&s, 40);
+    s.env.ledger().set_timestamp(1_001);
+    let round_id = open_round(&s, &distribution, 100);
+
+    distribution.distribute_batch(&s.dao.address, &round_id, &0, &1);
+    // A hands its already-paid shares to B, who has not been paid yet
+    s.shares.transfer(&a, &b, &60);
+    let round = distribution.distribute_batch(&s.dao.address, &round_id, &1, &1);
+
+    let paid = TokenClient::new(&s.env, &s.asset.address);
+    assert_eq!(paid.balance(&a), 60);
+    assert_eq!(paid.balance(&b), 40);
+    assert!(round.complete);
+    assert_eq!(round.paid, 100);
+    assert!(distribution
+        .try_distribute_batch(&s.dao.address, &round_id, &1, &1)
+        .is_err());
+}
+
+#[test]
+fn holders_outside_the_investor_list_are_paid() {
+    let (s, distribution) = setup();
+    let a = invest(&s, 100);
+    let c = Address::generate(&s.env);
+    s.shares.transfer(&a, &c, &30);
+    s.env.ledger().set_timestamp(1_001);
+    let round_id = open_round(&s, &distribution, 1_000);
+
+    let round = distribution.distribute_batch(&s.dao.address, &round_id, &0, &10);
+
+    let paid = TokenClient::new(&s.env, &s.asset.address);
+    assert_eq!(paid.balance(&a), 700);
+    assert_eq!(paid.balance(&c), 300);
+    assert!(round.complete);
+    assert_eq!(
+        s.dao.get_distribution_history(&0, &10),
+        vec![&s.env, (s.env.ledger().timestamp(), 1_000)]
+    );
+}
+
+#[test]
+fn claim_reinvests_after_the_raise_has_closed() {
+    let (s, distribution) = setup();
+    let a = invest(&s, 100);
+    distribution.set_reinvest(&s.dao.address, &a, &true);
+    s.env.ledger().set_timestamp(5_000);
+    assert!(s.env.ledger().timestamp() > s.dao.get_funding_deadline());
+
+    let round_id = open_round(&s, &distribution, 50);
+    distribution.distribute_batch(&s.dao.address, &round_id, &0, &10);
+    assert_eq!(
+        distribution.get_claimable(&s.dao.address, &a, &s.asset.address),
+        50
+    );
+
+    let reinvested = distribution.claim(&s.dao.address, &a, &s.asset.address);
+    let paid = TokenClient::new(&s.env, &s.asset.address);
+    assert_eq!(reinvested, 50);
+    assert_eq!(s.shares.balance(&a), 150);
+    assert_eq!(paid.balance(&a), 0);
+    assert_eq!(paid.balance(&s.dao.address), 150);
+    assert_eq!(s.dao.get_total_raised(), 100);
+    assert_eq!(s.dao.get_total_reinvested(), 50);
+}
+
+#[test]
+fn claim_pays_out_investors_the_dao_no_longer_admits() {
+    let (s, distribution) = setup();
+    let a = invest(&s, 100);
+    distribution.set_reinvest(&s.dao.address, &a, &true);
+    s.dao.set_investor_gate(&InvestorGate::Allowlist);
+    s.env.ledger().set_timestamp(1_001);
+    let round_id = open_round(&s, &distribution, 50);
+    distribution.distribute_batch(&s.dao.address, &round_id, &0, &10);
+
+    assert_eq!(distribution.claim(&s.dao.address, &a, &s.asset.address), 0);
+    assert_eq!(TokenClient::new(&s.env, &s.asset.address).balance(&a), 50);
+    assert_eq!(s.shares.balance(&a), 100);
+    assert_eq!(s.dao.get_total_reinvested(), 0);
+}
+
+#[test]
+fn claim_pays_out_once_reinvestment_is_off() {
+    let (s, distribution) = setup();
+    let a = invest(&s, 100);
+    distribution.set_reinvest(&s.dao.address, &a, &true);
+    s.env.ledger().set_timestamp(1_001);
+    let round_id = open_round(&s, &distribution, 50);
+    distribution.distribute_batch(&s.dao.address, &round_id, &0, &10);
+
+    distribution.set_reinvest(&s.dao.address, &a, &false);
+    assert_eq!(distribution.claim(&s.dao.address, &a, &s.asset.address), 0);
+    assert_eq!(TokenClient::new(&s.env, &s.asset.address).balance(&a), 50);
+    assert_eq!(s.shares.balance(&a), 100);
+}
+
+// Funds one genuine round of 5000 and sets up a lookalike DAO paying through
+// the same distribution contract
+fn setup_fake_dao<'a>() -> (
+    Setup<'a>,
+    ProfitDistributionContractClient<'a>,
+    Address,
+    Address,
+) {
+    let (s, distribution) = setup();
+    invest(&s, 100);
+    s.env.ledger().set_timestamp(1_001);
+    open_round(&s, &distribution, 5_000);
+
+    let attacker = Address::generate(&s.env);
+    let mut config = config(&s.env, &s.asset.address);
+    config.profit_distributor = distribution.address.clone();
+    let fake = s.env.register(FakeDao, (config, attacker.clone()));
+    s.asset.mint(&attacker, &1);
+    (s, distribution, fake, attacker)
+}
+
+#[test]
+fn rounds_for_unregistered_daos_are_refused() {
+    let (s, distribution, fake, attacker) = setup_fake_dao();
+
+    assert!(distribution
+        .try_open_round(&fake, &attacker, &s.asset.address, &1)
+        .is_err());
+    let paid = TokenClient::new(&s.env, &s.asset.address);
+    assert_eq!(paid.balance(&distribution.address), 5_000);
+    assert_eq!(paid.balance(&attacker), 1);
+}
+
+#[test]
+fn a_round_never_pays_more_than_it_escrowed() {
+    let (s, distribution, fake, attacker) = setup_fake_dao();
+    // Even a DAO the registry vouches for cannot reach other rounds' escrow
+    MockFactoryClient::new(&s.env, &s.factory).register_dao(&fake, &1);
+
+    let round_id = distribution.open_round(&fake, &attacker, &s.asset.address, &1);
+    assert!(distribution
+        .try_distribute_batch(&fake, &round_id, &0, &10)
+        .is_err());
+    let paid = TokenClient::new(&s.env, &s.asset.address);
+    assert_eq!(paid.balance(&distribution.address), 5_001);
+    assert_eq!(paid.balance(&attacker), 0);
+}
+
+#[test]
+fn rounds_for_daos_paying_elsewhere_are_refused() {
+    let s = setup_dao();
+    let distribution = ProfitDistributionContractClient::new(
+        &s.env,
+        &s.env
+            .register(ProfitDistributionContract, (s.factory.clone(),)),
+    );
+    initialize(&s, &config(&s.env, &s.asset.address));
+    invest(&s, 100);
+    s.env.ledger().set_timestamp(1_001);
+
+    let funder = Address::generate(&s.env);
+    s.asset.mint(&funder, &100);
+    assert!(distribution
+        .try_open_round(&s.dao.address, &funder, &s.asset.address, &100)
+        .is_err());
+}
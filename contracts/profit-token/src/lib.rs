@@ -1,5 +1,300 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, Map};
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error,
+    symbol_short, Address, Env, Map, String, Vec,
+};
+
+// Typed failures of token accounting
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    Overflow = 1,
+}
+
+fn add(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+fn sub(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_sub(b)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+}
+
+// Histories and per-holder records, kept in their own persistent entries
+// rather than in instance storage, which is loaded on every call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    // Balance history of one account
+    BalanceCheckpoints(Address),
+    // The n-th address ever to hold tokens
+    Holder(u32),
+    // Voting power history of one delegate
+    VoteCheckpoints(Address),
+    // Total supply history
+    SupplyCheckpoints,
+    // Who votes with an account's balance, when it has delegated
+    Delegate(Address),
+    // (unlock_time, amount) tranches of an account that have not unlocked yet
+    Lockups(Address),
+    // Present while the admin has frozen an account
+    Frozen(Address),
+}
+
+// Contract consulted before shares move to a new holder (normally the DAO,
+// when it only admits verified investors)
+#[contractclient(name = "TransferGateClient")]
+pub trait TransferGate {
+    fn is_investor_allowed(env: Env, account: Address) -> bool;
+}
+
+// Credit freshly minted tokens to `to`; callers check admin auth
+fn mint_balance(env: &Env, to: &Address, amount: i128) {
+    let storage = env.storage().instance();
+
+    // Amount must be positive
+    assert!(amount > 0, "Amount must be positive");
+    check_not_frozen(env, to);
+
+    // update total supply, staying within the cap if there is one
+    let total = add(env, storage.get(b"total_supply").unwrap(), amount);
+    let max_supply: Option<i128> = storage.get(b"max_supply");
+    if let Some(max_supply) = max_supply {
+        assert!(total <= max_supply, "Mint exceeds max supply");
+    }
+    storage.set(b"total_supply", &total);
+    checkpoint_supply(env, total);
+    // update balance
+    let mut balances: Map<Address, i128> = storage.get(b"balances").unwrap();
+    let prev: i128 = balances.get(to.clone()).unwrap_or(0);
+    set_balance(env, &mut balances, to, add(env, prev, amount));
+    storage.set(b"balances", &balances);
+    // new shares vote with the recipient's delegate
+    move_votes(env, None, Some(voting_delegate(env, to)), amount);
+}
+
+// Debit and destroy `amount` of `from`'s tokens; callers check auth
+fn burn_balance(env: &Env, from: &Address, amount: i128) {
+    let storage = env.storage().instance();
+
+    // Amount must be positive
+    assert!(amount > 0, "Amount must be positive");
+
+    // update balance
+    let mut balances: Map<Address, i128> = storage.get(b"balances").unwrap();
+    let prev: i128 = balances.get(from.clone()).unwrap_or(0);
+    assert!(prev >= amount, "Insufficient balance");
+
+    let balance = sub(env, prev, amount);
+    set_balance(env, &mut balances, from, balance);
+    storage.set(b"balances", &balances);
+    cap_lockups(env, from, balance);
+    move_votes(env, Some(voting_delegate(env, from)), None, amount);
+
+    // update total supply
+    let total = sub(env, storage.get(b"total_supply").unwrap(), amount);
+    storage.set(b"total_supply", &total);
+    checkpoint_supply(env, total);
+}
+
+// Require admin auth and that compliance controls were enabled at issuance
+fn require_compliance_admin(env: &Env) -> Address {
+    let storage = env.storage().instance();
+    let admin: Address = storage.get(b"admin").unwrap();
+    admin.require_auth();
+
+    let enabled: bool = storage.get(b"compliance_enabled").unwrap_or(false);
+    assert!(enabled, "Compliance controls are disabled");
+    admin
+}
+
+// Panic if `who` has been frozen by the admin
+fn check_not_frozen(env: &Env, who: &Address) {
+    assert!(
+        !env.storage()
+            .persistent()
+            .has(&DataKey::Frozen(who.clone())),
+        "Account is frozen"
+    );
+}
+
+// `who`'s lockup tranches that have not unlocked yet, dropping expired ones
+// from storage
+fn active_lockups(env: &Env, who: &Address) -> Vec<(u64, i128)> {
+    let tranches: Vec<(u64, i128)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Lockups(who.clone()))
+        .unwrap_or(Vec::new(env));
+
+    let now = env.ledger().timestamp();
+    let mut active: Vec<(u64, i128)> = Vec::new(env);
+    for (unlock_time, amount) in tranches.iter() {
+        if unlock_time > now {
+            active.push_back((unlock_time, amount));
+        }
+    }
+    if active.len() != tranches.len() {
+        save_lockups(env, who, &active);
+    }
+    active
+}
+
+fn save_lockups(env: &Env, who: &Address, tranches: &Vec<(u64, i128)>) {
+    let key = DataKey::Lockups(who.clone());
+    if tranches.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, tranches);
+    }
+}
+
+// Sum of `who`'s lockup tranches that have not unlocked yet
+fn locked_amount(env: &Env, who: &Address) -> i128 {
+    let mut locked = 0i128;
+    for (_, amount) in active_lockups(env, who).iter() {
+        locked = add(env, locked, amount);
+    }
+    locked
+}
+
+// After a burn, shrink `who`'s tranches so no more than `balance` stays locked.
+// Burns come out of the unlocked part first, then out of the latest tranches.
+fn cap_lockups(env: &Env, who: &Address, balance: i128) {
+    let mut tranches = active_lockups(env, who);
+    let mut excess = -balance;
+    for (_, amount) in tranches.iter() {
+        excess = add(env, excess, amount);
+    }
+    if excess <= 0 {
+        return;
+    }
+    while excess > 0 {
+        let (unlock_time, amount) = tranches.pop_back().unwrap();
+        if amount > excess {
+            tranches.push_back((unlock_time, amount - excess));
+        }
+        excess = sub(env, excess, amount);
+    }
+    save_lockups(env, who, &tranches);
+}
+
+// Panic unless transfers are enabled and `amount` is within the unlocked part
+// of `from`'s balance
+fn check_transferable(env: &Env, from: &Address, from_balance: i128, amount: i128) {
+    let enabled: bool = env
+        .storage()
+        .instance()
+        .get(b"transfers_enabled")
+        .unwrap_or(true);
+    assert!(enabled, "Transfers are disabled");
+    assert!(
+        sub(env, from_balance, locked_amount(env, from)) >= amount,
+        "Amount exceeds unlocked balance"
+    );
+}
+
+// Panic unless the transfer gate, if one is set, admits `to`
+fn check_transfer_gate(env: &Env, to: &Address) {
+    let gate: Option<Address> = env.storage().instance().get(b"transfer_gate");
+    if let Some(gate) = gate {
+        assert!(
+            TransferGateClient::new(env, &gate).is_investor_allowed(to),
+            "Recipient is not an allowed investor"
+        );
+    }
+}
+
+// Voting power history of one account: (timestamp, votes) pairs in time order
+type Checkpoints = Vec<(u64, i128)>;
+
+// Record `votes` as of now, overwriting a checkpoint already written this ledger
+fn push_checkpoint(env: &Env, checkpoints: &mut Checkpoints, votes: i128) {
+    let now = env.ledger().timestamp();
+    match checkpoints.last() {
+        Some((ts, _)) if ts == now => checkpoints.set(checkpoints.len() - 1, (now, votes)),
+        _ => checkpoints.push_back((now, votes)),
+    }
+}
+
+// Value of the latest checkpoint at or before `timestamp`
+fn checkpoint_at(checkpoints: &Checkpoints, timestamp: u64) -> i128 {
+    let (mut lo, mut hi) = (0u32, checkpoints.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if checkpoints.get_unchecked(mid).0 <= timestamp {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    if lo == 0 {
+        0
+    } else {
+        checkpoints.get_unchecked(lo - 1).1
+    }
+}
+
+// Whoever votes with `who`'s balance: their delegatee, or themselves by default
+fn voting_delegate(env: &Env, who: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Delegate(who.clone()))
+        .unwrap_or(who.clone())
+}
+
+// Shift `amount` of voting power between delegates; None stands for mint/burn
+fn move_votes(env: &Env, from: Option<Address>, to: Option<Address>, amount: i128) {
+    if from == to || amount == 0 {
+        return;
+    }
+
+    let persistent = env.storage().persistent();
+    if let Some(from) = from {
+        let key = DataKey::VoteCheckpoints(from);
+        let mut cps: Checkpoints = persistent.get(&key).unwrap_or(Vec::new(env));
+        let votes = cps.last().map(|(_, v)| v).unwrap_or(0);
+        push_checkpoint(env, &mut cps, sub(env, votes, amount));
+        persistent.set(&key, &cps);
+    }
+    if let Some(to) = to {
+        let key = DataKey::VoteCheckpoints(to);
+        let mut cps: Checkpoints = persistent.get(&key).unwrap_or(Vec::new(env));
+        let votes = cps.last().map(|(_, v)| v).unwrap_or(0);
+        push_checkpoint(env, &mut cps, add(env, votes, amount));
+        persistent.set(&key, &cps);
+    }
+}
+
+// Write `who`'s new balance and record it as of now, indexing `who` as a
+// holder the first time they hold tokens
+fn set_balance(env: &Env, balances: &mut Map<Address, i128>, who: &Address, balance: i128) {
+    balances.set(who.clone(), balance);
+
+    let persistent = env.storage().persistent();
+    let key = DataKey::BalanceCheckpoints(who.clone());
+    let mut cps: Checkpoints = persistent.get(&key).unwrap_or(Vec::new(env));
+    if cps.is_empty() {
+        let storage = env.storage().instance();
+        let count: u32 = storage.get(b"holder_count").unwrap_or(0);
+        persistent.set(&DataKey::Holder(count), who);
+        storage.set(b"holder_count", &(count + 1));
+    }
+    push_checkpoint(env, &mut cps, balance);
+    persistent.set(&key, &cps);
+}
+
+// Record the total supply as of now
+fn checkpoint_supply(env: &Env, total: i128) {
+    let persistent = env.storage().persistent();
+    let mut cps: Checkpoints = persistent
+        .get(&DataKey::SupplyCheckpoints)
+        .unwrap_or(Vec::new(env));
+    push_checkpoint(env, &mut cps, total);
+    persistent.set(&DataKey::SupplyCheckpoints, &cps);
+}
 
 #[contract]
 pub struct ProfitTokenContract;
@@ -8,10 +303,25 @@ pub struct ProfitTokenContract;
 impl ProfitTokenContract {
     // Initialize the token with the DAO contract as admin
     // admin: the DAO contract address (Address::Contract)
-    pub fn initialize_ptc(env: Env, admin: Address) {
+    // compliance_enabled: whether the admin may freeze and claw back tokens;
+    // fixed at issuance
+    pub fn initialize_ptc(
+        env: Env,
+        admin: Address,
+        name: String,
+        symbol: String,
+        compliance_enabled: bool,
+    ) {
         let storage = env.storage().instance();
-        // Store admin for authorization (optional)
+        assert!(!storage.has(b"admin"), "Token already initialized");
+        admin.require_auth();
+
+        // Store admin for authorization
         storage.set(b"admin", &admin);
+        // Token metadata, chosen per DAO
+        storage.set(b"name", &name);
+        storage.set(b"symbol", &symbol);
+        storage.set(b"compliance_enabled", &compliance_enabled);
         // Total supply starts at zero
         storage.set(b"total_supply", &0i128);
         // Balances map: Address -> i128
@@ -26,6 +336,59 @@ impl ProfitTokenContract {
         storage.get(b"admin").unwrap()
     }
 
+    // Hand the admin role to a new address (e.g. when the DAO migrates)
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let storage = env.storage().instance();
+        let admin: Address = storage.get(b"admin").unwrap();
+        admin.require_auth();
+
+        storage.set(b"admin", &new_admin);
+    }
+
+    // Cap the total supply, or remove the cap with None
+    pub fn set_max_supply(env: Env, max_supply: Option<i128>) {
+        let storage = env.storage().instance();
+        let admin: Address = storage.get(b"admin").unwrap();
+        admin.require_auth();
+
+        match max_supply {
+            Some(max_supply) => {
+                let total: i128 = storage.get(b"total_supply").unwrap();
+                assert!(max_supply >= total, "Max supply below current total supply");
+                storage.set(b"max_supply", &max_supply);
+            }
+            None => storage.remove(b"max_supply"),
+        }
+    }
+
+    pub fn get_max_supply(env: Env) -> Option<i128> {
+        env.storage().instance().get(b"max_supply")
+    }
+
+    pub fn name(env: Env) -> String {
+        env.storage().instance().get(b"name").unwrap()
+    }
+
+    pub fn symbol(env: Env) -> String {
+        env.storage().instance().get(b"symbol").unwrap()
+    }
+
+    // Set (or clear) the contract that must approve every transfer recipient
+    pub fn set_transfer_gate(env: Env, gate: Option<Address>) {
+        let storage = env.storage().instance();
+        let admin: Address = storage.get(b"admin").unwrap();
+        admin.require_auth();
+
+        match gate {
+            Some(gate) => storage.set(b"transfer_gate", &gate),
+            None => storage.remove(b"transfer_gate"),
+        }
+    }
+
+    pub fn get_transfer_gate(env: Env) -> Option<Address> {
+        env.storage().instance().get(b"transfer_gate")
+    }
+
     // Only the DAO contract (admin) should call mint
     pub fn mint(env: Env, to: Address, amount: i128) {
         let storage = env.storage().instance();
@@ -34,42 +397,119 @@ impl ProfitTokenContract {
         let admin: Address = storage.get(b"admin").unwrap();
         admin.require_auth();
 
-        // Amount must be positive
-        assert!(amount > 0, "Amount must be positive");
+        mint_balance(&env, &to, amount);
+    }
 
-        // update total supply
-        let mut total: i128 = storage.get(b"total_supply").unwrap();
-        total += amount;
-        storage.set(b"total_supply", &total);
-        // update balance
-        let mut balances: Map<Address, i128> = storage.get(b"balances").unwrap();
-        let prev: i128 = balances.get(to.clone()).unwrap_or(0);
-        balances.set(to, prev + amount);
-        storage.set(b"balances", &balances);
+    // Mint to many holders at once, e.g. for an initial allocation
+    pub fn mint_batch(env: Env, recipients: Vec<(Address, i128)>) {
+        let storage = env.storage().instance();
+
+        // Check authorization - Only admin can mint
+        let admin: Address = storage.get(b"admin").unwrap();
+        admin.require_auth();
+
+        for (to, amount) in recipients.iter() {
+            mint_balance(&env, &to, amount);
+        }
     }
 
-    // Burn tokens
-    pub fn burn(env: Env, from: Address, amount: i128) {
+    // Mint tokens that `to` cannot transfer before `unlock_time`. Locked tokens
+    // still vote and still earn distributions.
+    pub fn mint_locked(env: Env, to: Address, amount: i128, unlock_time: u64) {
         let storage = env.storage().instance();
 
+        // Check authorization - Only admin can mint
+        let admin: Address = storage.get(b"admin").unwrap();
+        admin.require_auth();
+
+        mint_balance(&env, &to, amount);
+
+        if unlock_time > env.ledger().timestamp() {
+            let mut tranches = active_lockups(&env, &to);
+            tranches.push_back((unlock_time, amount));
+            save_lockups(&env, &to, &tranches);
+        }
+    }
+
+    // Turn transfers between holders on or off for everyone
+    pub fn set_transfers_enabled(env: Env, enabled: bool) {
+        let storage = env.storage().instance();
+        let admin: Address = storage.get(b"admin").unwrap();
+        admin.require_auth();
+
+        storage.set(b"transfers_enabled", &enabled);
+    }
+
+    pub fn transfers_enabled(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(b"transfers_enabled")
+            .unwrap_or(true)
+    }
+
+    // Part of `who`'s balance that is still under lockup
+    pub fn locked_balance(env: Env, who: Address) -> i128 {
+        locked_amount(&env, &who)
+    }
+
+    // Tranches of `who` that are still locked, as (unlock_time, amount) pairs
+    pub fn get_lockups(env: Env, who: Address) -> Vec<(u64, i128)> {
+        active_lockups(&env, &who)
+    }
+
+    // Burn tokens
+    pub fn burn(env: Env, from: Address, amount: i128) {
         // Require authorization from the token owner
         from.require_auth();
+        check_not_frozen(&env, &from);
 
-        // Amount must be positive
-        assert!(amount > 0, "Amount must be positive");
+        burn_balance(&env, &from, amount);
+    }
 
-        // update balance
-        let mut balances: Map<Address, i128> = storage.get(b"balances").unwrap();
-        let prev: i128 = balances.get(from.clone()).unwrap_or(0);
-        assert!(prev >= amount, "Insufficient balance");
+    pub fn compliance_enabled(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(b"compliance_enabled")
+            .unwrap_or(false)
+    }
 
-        balances.set(from, prev - amount);
-        storage.set(b"balances", &balances);
+    // Stop `account` from sending, receiving or burning tokens
+    pub fn freeze(env: Env, account: Address) {
+        let admin = require_compliance_admin(&env);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Frozen(account.clone()), &true);
+
+        env.events()
+            .publish((symbol_short!("freeze"), admin, account), ());
+    }
+
+    // Lift a freeze
+    pub fn unfreeze(env: Env, account: Address) {
+        let admin = require_compliance_admin(&env);
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Frozen(account.clone()));
+
+        env.events()
+            .publish((symbol_short!("unfreeze"), admin, account), ());
+    }
+
+    pub fn is_frozen(env: Env, account: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Frozen(account))
+    }
+
+    // Burn `amount` from `from` without its consent, ignoring freezes and
+    // consuming lockups
+    pub fn clawback(env: Env, from: Address, amount: i128) {
+        let admin = require_compliance_admin(&env);
+
+        burn_balance(&env, &from, amount);
 
-        // update total supply
-        let mut total: i128 = storage.get(b"total_supply").unwrap();
-        total -= amount;
-        storage.set(b"total_supply", &total);
+        env.events()
+            .publish((symbol_short!("clawback"), admin, from), amount);
     }
 
     // Transfer tokens to another address
@@ -79,6 +519,9 @@ impl ProfitTokenContract {
 
         // Amount must be positive
         assert!(amount > 0, "Amount must be positive");
+        check_transfer_gate(&env, &to);
+        check_not_frozen(&env, &from);
+        check_not_frozen(&env, &to);
 
         let storage = env.storage().instance();
         let mut balances: Map<Address, i128> = storage.get(b"balances").unwrap();
@@ -86,13 +529,20 @@ impl ProfitTokenContract {
         // Check sender has enough balance
         let from_balance = balances.get(from.clone()).unwrap_or(0);
         assert!(from_balance >= amount, "Insufficient balance");
+        check_transferable(&env, &from, from_balance, amount);
 
         // Update balances
-        balances.set(from.clone(), from_balance - amount);
+        set_balance(&env, &mut balances, &from, sub(&env, from_balance, amount));
         let to_balance = balances.get(to.clone()).unwrap_or(0);
-        balances.set(to, to_balance + amount);
+        set_balance(&env, &mut balances, &to, add(&env, to_balance, amount));
 
         storage.set(b"balances", &balances);
+        move_votes(
+            &env,
+            Some(voting_delegate(&env, &from)),
+            Some(voting_delegate(&env, &to)),
+            amount,
+        );
     }
 
     // Approve spender to transfer tokens on behalf of owner
@@ -125,6 +575,9 @@ impl ProfitTokenContract {
 
         // Amount must be positive
         assert!(amount > 0, "Amount must be positive");
+        check_transfer_gate(&env, &to);
+        check_not_frozen(&env, &from);
+        check_not_frozen(&env, &to);
 
         let storage = env.storage().instance();
 
@@ -134,19 +587,108 @@ impl ProfitTokenContract {
         assert!(allowance >= amount, "Insufficient allowance");
 
         // Update allowance
-        allowances.set((from.clone(), spender), allowance - amount);
+        allowances.set((from.clone(), spender), sub(&env, allowance, amount));
         storage.set(b"allowances", &allowances);
 
         // Update balances
         let mut balances: Map<Address, i128> = storage.get(b"balances").unwrap();
         let from_balance = balances.get(from.clone()).unwrap_or(0);
         assert!(from_balance >= amount, "Insufficient balance");
+        check_transferable(&env, &from, from_balance, amount);
 
-        balances.set(from, from_balance - amount);
+        set_balance(&env, &mut balances, &from, sub(&env, from_balance, amount));
         let to_balance = balances.get(to.clone()).unwrap_or(0);
-        balances.set(to, to_balance + amount);
+        set_balance(&env, &mut balances, &to, add(&env, to_balance, amount));
 
         storage.set(b"balances", &balances);
+        move_votes(
+            &env,
+            Some(voting_delegate(&env, &from)),
+            Some(voting_delegate(&env, &to)),
+            amount,
+        );
+    }
+
+    // Let `delegatee` vote with the delegator's balance; delegating to yourself
+    // takes the votes back
+    pub fn delegate(env: Env, delegator: Address, delegatee: Address) {
+        delegator.require_auth();
+
+        let persistent = env.storage().persistent();
+        let previous = voting_delegate(&env, &delegator);
+
+        let key = DataKey::Delegate(delegator.clone());
+        if delegatee == delegator {
+            persistent.remove(&key);
+        } else {
+            persistent.set(&key, &delegatee);
+        }
+
+        let balance = Self::balance(env.clone(), delegator);
+        move_votes(&env, Some(previous), Some(delegatee), balance);
+    }
+
+    // Who votes with `who`'s balance, if they have delegated
+    pub fn delegates(env: Env, who: Address) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Delegate(who))
+    }
+
+    // Current voting power: own undelegated balance plus balances delegated in
+    pub fn get_votes(env: Env, who: Address) -> i128 {
+        let cps: Checkpoints = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VoteCheckpoints(who))
+            .unwrap_or(Vec::new(&env));
+        cps.last().map(|(_, v)| v).unwrap_or(0)
+    }
+
+    // Voting power as of `timestamp`
+    pub fn get_past_votes(env: Env, who: Address, timestamp: u64) -> i128 {
+        let cps: Checkpoints = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VoteCheckpoints(who))
+            .unwrap_or(Vec::new(&env));
+        checkpoint_at(&cps, timestamp)
+    }
+
+    // Total supply as of `timestamp`
+    pub fn get_past_total_supply(env: Env, timestamp: u64) -> i128 {
+        let cps: Checkpoints = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SupplyCheckpoints)
+            .unwrap_or(Vec::new(&env));
+        checkpoint_at(&cps, timestamp)
+    }
+
+    // Balance of `who` as of `timestamp`
+    pub fn get_past_balance(env: Env, who: Address, timestamp: u64) -> i128 {
+        let cps: Checkpoints = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BalanceCheckpoints(who))
+            .unwrap_or(Vec::new(&env));
+        checkpoint_at(&cps, timestamp)
+    }
+
+    // Number of addresses that have ever held tokens
+    pub fn holder_count(env: Env) -> u32 {
+        env.storage().instance().get(b"holder_count").unwrap_or(0)
+    }
+
+    // Up to `limit` addresses that have ever held tokens, in the order they
+    // first received them, starting at position `start`
+    pub fn get_holders(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::holder_count(env.clone()));
+        let mut holders: Vec<Address> = Vec::new(&env);
+        for i in start..end {
+            holders.push_back(env.storage().persistent().get(&DataKey::Holder(i)).unwrap());
+        }
+        holders
     }
 
     pub fn balance(env: Env, who: Address) -> i128 {
@@ -158,3 +700,6 @@ impl ProfitTokenContract {
         env.storage().instance().get(b"total_supply").unwrap()
     }
 }
+
+#[cfg(test)]
+mod test;
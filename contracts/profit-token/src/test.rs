@@ -0,0 +1,244 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+};
+
+fn setup<'a>(env: &Env) -> ProfitTokenContractClient<'a> {
+    setup_with_compliance(env, false)
+}
+
+fn setup_with_compliance<'a>(env: &Env, compliance: bool) -> ProfitTokenContractClient<'a> {
+    env.mock_all_auths();
+    let token = ProfitTokenContractClient::new(env, &env.register(ProfitTokenContract, ()));
+    token.initialize_ptc(
+        &Address::generate(env),
+        &String::from_str(env, "Share"),
+        &String::from_str(env, "SHR"),
+        &compliance,
+    );
+    token
+}
+
+#[test]
+fn mint_up_to_i128_max() {
+    let env = Env::default();
+    let token = setup(&env);
+    let holder = Address::generate(&env);
+
+    token.mint(&holder, &(i128::MAX - 1));
+    token.mint(&holder, &1);
+    assert_eq!(token.balance(&holder), i128::MAX);
+    assert_eq!(token.total_supply(), i128::MAX);
+    assert_eq!(token.get_votes(&holder), i128::MAX);
+}
+
+#[test]
+fn mint_past_i128_max_overflows() {
+    let env = Env::default();
+    let token = setup(&env);
+    let holder = Address::generate(&env);
+    token.mint(&holder, &i128::MAX);
+
+    let other = Address::generate(&env);
+    assert_eq!(token.try_mint(&other, &1), Err(Ok(Error::Overflow.into())));
+    assert_eq!(token.total_supply(), i128::MAX);
+    assert_eq!(token.balance(&other), 0);
+}
+
+#[test]
+fn transfer_whole_i128_max_balance() {
+    let env = Env::default();
+    let token = setup(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    token.mint(&from, &i128::MAX);
+
+    token.transfer(&from, &to, &i128::MAX);
+    assert_eq!(token.balance(&from), 0);
+    assert_eq!(token.balance(&to), i128::MAX);
+    assert_eq!(token.get_votes(&from), 0);
+    assert_eq!(token.get_votes(&to), i128::MAX);
+}
+
+#[test]
+fn transfer_from_whole_i128_max_balance() {
+    let env = Env::default();
+    let token = setup(&env);
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let to = Address::generate(&env);
+    token.mint(&from, &i128::MAX);
+    token.approve(&from, &spender, &i128::MAX);
+
+    token.transfer_from(&spender, &from, &to, &i128::MAX);
+    assert_eq!(token.allowance(&from, &spender), 0);
+    assert_eq!(token.balance(&to), i128::MAX);
+}
+
+#[test]
+fn transfer_above_balance_fails() {
+    let env = Env::default();
+    let token = setup(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    token.mint(&from, &i128::MAX);
+    token.transfer(&from, &to, &1);
+
+    assert!(token.try_transfer(&from, &to, &i128::MAX).is_err());
+    assert_eq!(token.balance(&to), 1);
+}
+
+#[test]
+fn initialize_twice_fails() {
+    let env = Env::default();
+    let token = setup(&env);
+    assert!(token
+        .try_initialize_ptc(
+            &Address::generate(&env),
+            &String::from_str(&env, "Other"),
+            &String::from_str(&env, "OTH"),
+            &true,
+        )
+        .is_err());
+    assert!(!token.compliance_enabled());
+}
+
+#[test]
+fn delegation_moves_votes_and_keeps_history() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100);
+    let token = setup(&env);
+    let holder = Address::generate(&env);
+    let delegatee = Address::generate(&env);
+    token.mint(&holder, &40);
+
+    env.ledger().set_timestamp(200);
+    token.delegate(&holder, &delegatee);
+    assert_eq!(token.delegates(&holder), Some(delegatee.clone()));
+    assert_eq!(token.get_votes(&holder), 0);
+    assert_eq!(token.get_votes(&delegatee), 40);
+
+    // New shares follow the delegate
+    env.ledger().set_timestamp(300);
+    token.mint(&holder, &10);
+    assert_eq!(token.get_votes(&delegatee), 50);
+
+    // Delegating to yourself takes the votes back
+    env.ledger().set_timestamp(400);
+    token.delegate(&holder, &holder);
+    assert_eq!(token.delegates(&holder), None);
+    assert_eq!(token.get_votes(&holder), 50);
+    assert_eq!(token.get_votes(&delegatee), 0);
+
+    assert_eq!(token.get_past_votes(&holder, &99), 0);
+    assert_eq!(token.get_past_votes(&holder, &150), 40);
+    assert_eq!(token.get_past_votes(&delegatee, &250), 40);
+    assert_eq!(token.get_past_votes(&delegatee, &300), 50);
+    assert_eq!(token.get_past_votes(&delegatee, &400), 0);
+    assert_eq!(token.get_past_total_supply(&250), 40);
+    assert_eq!(token.get_past_total_supply(&300), 50);
+}
+
+#[test]
+fn expired_lockups_are_dropped_on_read() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100);
+    let token = setup(&env);
+    let holder = Address::generate(&env);
+    let other = Address::generate(&env);
+    token.mint_locked(&holder, &30, &200);
+    token.mint_locked(&holder, &20, &300);
+
+    assert_eq!(token.locked_balance(&holder), 50);
+    assert!(token.try_transfer(&holder, &other, &1).is_err());
+
+    env.ledger().set_timestamp(200);
+    assert_eq!(token.get_lockups(&holder), vec![&env, (300, 20)]);
+    token.transfer(&holder, &other, &30);
+    assert!(token.try_transfer(&holder, &other, &1).is_err());
+
+    env.ledger().set_timestamp(300);
+    assert_eq!(token.locked_balance(&holder), 0);
+    env.as_contract(&token.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::Lockups(holder.clone())));
+    });
+    token.transfer(&holder, &other, &20);
+}
+
+#[test]
+fn burn_consumes_locked_tranches() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100);
+    let token = setup(&env);
+    let holder = Address::generate(&env);
+    token.mint(&holder, &10);
+    token.mint_locked(&holder, &30, &200);
+    token.mint_locked(&holder, &20, &300);
+
+    // The unlocked 10 go first, then the latest tranche
+    token.burn(&holder, &25);
+    assert_eq!(token.balance(&holder), 35);
+    assert_eq!(token.get_lockups(&holder), vec![&env, (200, 30), (300, 5)]);
+
+    token.burn(&holder, &35);
+    assert_eq!(token.locked_balance(&holder), 0);
+    assert_eq!(token.get_lockups(&holder), Vec::new(&env));
+}
+
+#[test]
+fn clawback_caps_locked_at_balance() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100);
+    let token = setup_with_compliance(&env, true);
+    let holder = Address::generate(&env);
+    let other = Address::generate(&env);
+    token.mint_locked(&holder, &50, &200);
+
+    token.clawback(&holder, &40);
+    assert_eq!(token.balance(&holder), 10);
+    assert_eq!(token.locked_balance(&holder), 10);
+
+    // Newly received tokens are not held back by the clawed-back lockup
+    token.mint(&holder, &5);
+    token.transfer(&holder, &other, &5);
+    assert!(token.try_transfer(&holder, &other, &1).is_err());
+}
+
+#[test]
+fn frozen_account_cannot_move_tokens() {
+    let env = Env::default();
+    let token = setup_with_compliance(&env, true);
+    let holder = Address::generate(&env);
+    let other = Address::generate(&env);
+    token.mint(&holder, &10);
+    token.mint(&other, &10);
+
+    token.freeze(&holder);
+    assert!(token.is_frozen(&holder));
+    assert!(token.try_transfer(&holder, &other, &1).is_err());
+    assert!(token.try_transfer(&other, &holder, &1).is_err());
+    assert!(token.try_burn(&holder, &1).is_err());
+    assert!(token.try_mint(&holder, &1).is_err());
+
+    token.unfreeze(&holder);
+    assert!(!token.is_frozen(&holder));
+    env.as_contract(&token.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::Frozen(holder.clone())));
+    });
+    token.transfer(&holder, &other, &1);
+}
+
+#[test]
+fn freeze_requires_compliance() {
+    let env = Env::default();
+    let token = setup(&env);
+    assert!(token.try_freeze(&Address::generate(&env)).is_err());
+}